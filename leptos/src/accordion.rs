@@ -22,7 +22,7 @@ use leptos::*;
 /// - `default_open`: bool - Si empieza abierto (default: false)
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui_leptos::*;
 ///
 /// view! {
@@ -126,3 +126,26 @@ pub fn Accordion(
         </div>
     }
 }
+
+/// Chevron del Accordion con estado fijo (apunta arriba cuando está abierto)
+///
+/// Usado por las filas expandibles de Table, que se re-renderizan al cambiar.
+pub(crate) fn accordion_chevron(open: bool, size_class: &'static str) -> impl IntoView {
+    let rotate_class = if open { "rotate-180" } else { "" };
+
+    view! {
+        <svg
+            class=format!("{} text-text-tertiary transition-transform {}", size_class, rotate_class)
+            fill="none"
+            stroke="currentColor"
+            viewBox="0 0 24 24"
+        >
+            <path
+                stroke-linecap="round"
+                stroke-linejoin="round"
+                stroke-width="2"
+                d="M19 9l-7 7-7-7"
+            />
+        </svg>
+    }
+}
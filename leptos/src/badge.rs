@@ -17,7 +17,7 @@ use leptos::*;
 /// - `text`: String - Contenido textual
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui_leptos::*;
 ///
 /// view! {
@@ -19,7 +19,7 @@ use leptos::*;
 /// - `children`: Children - Contenido (texto/iconos)
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui_leptos::*;
 ///
 /// view! {
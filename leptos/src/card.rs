@@ -18,7 +18,7 @@ use leptos::*;
 /// - `children`: Children - Contenido del card
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui_leptos::*;
 ///
 /// view! {
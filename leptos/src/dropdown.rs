@@ -56,14 +56,14 @@ pub fn Dropdown(
             // For simplicity, we're not storing the closures here
             let _ = document.add_event_listener_with_callback(
                 "keydown",
-                wasm_bindgen::closure::Closure::wrap(Box::new(on_keydown.clone()) as Box<dyn Fn(KeyboardEvent)>)
+                wasm_bindgen::closure::Closure::wrap(Box::new(on_keydown) as Box<dyn Fn(KeyboardEvent)>)
                     .as_ref()
                     .unchecked_ref(),
             );
 
             let _ = document.add_event_listener_with_callback(
                 "click",
-                wasm_bindgen::closure::Closure::wrap(Box::new(on_document_click.clone()) as Box<dyn Fn(MouseEvent)>)
+                wasm_bindgen::closure::Closure::wrap(Box::new(on_document_click) as Box<dyn Fn(MouseEvent)>)
                     .as_ref()
                     .unchecked_ref(),
             );
@@ -0,0 +1,344 @@
+//! Formato de números financieros
+//!
+//! Misma spec que yew/src/format.rs (ver ahí la documentación completa):
+//! en Leptos la recibe `TableCell::numeric`. Es Rust puro, sin DOM.
+
+/// Locale de los separadores y espacios del formato
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Locale {
+    /// 1,234.56 · $1,234.56 · 12.5%
+    #[default]
+    EnUs,
+    /// 1.234,56 · $ 1.234,56 · 12,5 %
+    EsAr,
+}
+
+impl Locale {
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Locale::EnUs => '.',
+            Locale::EsAr => ',',
+        }
+    }
+
+    pub fn thousands_separator(&self) -> char {
+        match self {
+            Locale::EnUs => ',',
+            Locale::EsAr => '.',
+        }
+    }
+
+    /// Espacio entre el número y el símbolo de moneda o de porcentaje
+    fn symbol_gap(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "",
+            Locale::EsAr => " ",
+        }
+    }
+}
+
+/// Unidad del valor formateado
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum NumberUnit {
+    /// Número solo (más `prefix`/`suffix` si hay)
+    #[default]
+    Plain,
+    /// Símbolo de moneda antes del número
+    Currency(String),
+    /// Valor en puntos porcentuales (12.5 → "12.5%")
+    Percent,
+    /// Valor en puntos porcentuales mostrado en basis points (0.25 → "25 bps")
+    BasisPoints,
+}
+
+/// Formato de un número: la spec que reciben los componentes junto al
+/// valor crudo
+///
+/// ```
+/// use hubermann_ui_leptos::NumberFormat;
+///
+/// let price = NumberFormat::new(2).with_prefix("$");
+/// assert_eq!(price.format(1182.456), "$1,182.46");
+/// assert_eq!(price.format(-0.5), "-$0.50");
+///
+/// let change = NumberFormat::new(1).with_suffix("%").with_sign(true);
+/// assert_eq!(change.format(3.14), "+3.1%");
+/// assert_eq!(change.format(-0.01), "0.0%");
+///
+/// assert_eq!(NumberFormat::new(0).with_thousands(false).format(25000.0), "25000");
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct NumberFormat {
+    /// Decimales fijos
+    pub decimals: usize,
+    /// Texto antes del número (ej: "≈ ")
+    pub prefix: String,
+    /// Texto después del número (ej: " BTC")
+    pub suffix: String,
+    /// Separador de miles (default: true)
+    pub thousands: bool,
+    /// Muestra "+" en positivos (default: false)
+    pub signed: bool,
+    /// Moneda, porcentaje o basis points
+    pub unit: NumberUnit,
+    /// Notación compacta K/M/B (default: false)
+    pub compact: bool,
+    pub locale: Locale,
+}
+
+impl NumberFormat {
+    /// Precisión fija con separador de miles
+    pub fn new(decimals: usize) -> Self {
+        Self {
+            decimals,
+            prefix: String::new(),
+            suffix: String::new(),
+            thousands: true,
+            signed: false,
+            unit: NumberUnit::Plain,
+            compact: false,
+            locale: Locale::EnUs,
+        }
+    }
+
+    /// Moneda con el símbolo antes del número
+    ///
+    /// ```
+    /// use hubermann_ui_leptos::{Locale, NumberFormat};
+    ///
+    /// assert_eq!(NumberFormat::currency("$", 2).format(45231.894), "$45,231.89");
+    /// assert_eq!(NumberFormat::currency("$", 2).format(-12.5), "-$12.50");
+    /// assert_eq!(NumberFormat::currency("$", 2).with_locale(Locale::EsAr).format(45231.894), "$ 45.231,89");
+    /// ```
+    pub fn currency(symbol: impl Into<String>, decimals: usize) -> Self {
+        Self {
+            unit: NumberUnit::Currency(symbol.into()),
+            ..Self::new(decimals)
+        }
+    }
+
+    /// Porcentaje (el valor ya viene en puntos porcentuales)
+    ///
+    /// ```
+    /// use hubermann_ui_leptos::{Locale, NumberFormat};
+    ///
+    /// assert_eq!(NumberFormat::percent(1).format(12.5), "12.5%");
+    /// assert_eq!(NumberFormat::percent(1).with_locale(Locale::EsAr).format(12.5), "12,5 %");
+    /// ```
+    pub fn percent(decimals: usize) -> Self {
+        Self {
+            unit: NumberUnit::Percent,
+            ..Self::new(decimals)
+        }
+    }
+
+    /// Cambio porcentual con signo explícito
+    ///
+    /// ```
+    /// use hubermann_ui_leptos::NumberFormat;
+    ///
+    /// assert_eq!(NumberFormat::change(1).format(12.5), "+12.5%");
+    /// assert_eq!(NumberFormat::change(1).format(-3.04), "-3.0%");
+    /// assert_eq!(NumberFormat::change(1).format(0.0), "0.0%");
+    /// ```
+    pub fn change(decimals: usize) -> Self {
+        Self::percent(decimals).with_sign(true)
+    }
+
+    /// Notación compacta: 1.2K, 3.4M, 5.6B. Sin letra (menos de 1.000) no
+    /// lleva decimales, como un eje de volumen
+    ///
+    /// ```
+    /// use hubermann_ui_leptos::{Locale, NumberFormat};
+    ///
+    /// assert_eq!(NumberFormat::compact(1).format(1_234_567.0), "1.2M");
+    /// assert_eq!(NumberFormat::compact(1).format(999_960.0), "1.0M");
+    /// assert_eq!(NumberFormat::compact(1).format(950.0), "950");
+    /// assert_eq!(NumberFormat::compact(1).with_locale(Locale::EsAr).format(4_500.0), "4,5K");
+    /// assert_eq!(NumberFormat::currency("$", 2).with_compact(true).format(2.5e9), "$2.50B");
+    /// ```
+    pub fn compact(decimals: usize) -> Self {
+        Self::new(decimals).with_compact(true)
+    }
+
+    /// Basis points (el valor viene en puntos porcentuales: 0.25 → 25 bps)
+    ///
+    /// ```
+    /// use hubermann_ui_leptos::NumberFormat;
+    ///
+    /// assert_eq!(NumberFormat::bps(0).format(0.25), "25 bps");
+    /// assert_eq!(NumberFormat::bps(0).with_sign(true).format(-1.5), "-150 bps");
+    /// ```
+    pub fn bps(decimals: usize) -> Self {
+        Self {
+            unit: NumberUnit::BasisPoints,
+            ..Self::new(decimals)
+        }
+    }
+
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    pub fn with_thousands(mut self, thousands: bool) -> Self {
+        self.thousands = thousands;
+        self
+    }
+
+    pub fn with_sign(mut self, signed: bool) -> Self {
+        self.signed = signed;
+        self
+    }
+
+    pub fn with_compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Si el valor se muestra como cero (y por lo tanto sin signo ni color)
+    ///
+    /// ```
+    /// use hubermann_ui_leptos::NumberFormat;
+    ///
+    /// assert!(NumberFormat::change(2).rounds_to_zero(-0.001));
+    /// assert!(!NumberFormat::bps(0).rounds_to_zero(0.01));
+    /// ```
+    pub fn rounds_to_zero(&self, value: f64) -> bool {
+        let (fixed, _) = self.rounded(self.scaled(value).abs());
+        fixed.chars().all(|c| c == '0' || c == '.')
+    }
+
+    /// Texto del valor. NaN e infinito (un dato faltante o una división por
+    /// cero) se muestran como "—"
+    pub fn format(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return "—".to_string();
+        }
+
+        let value = self.scaled(value);
+        let (fixed, letter) = self.rounded(value.abs());
+        let (integer, fraction) = match fixed.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (fixed.as_str(), None),
+        };
+
+        let mut digits = String::with_capacity(fixed.len() + integer.len() / 3);
+        for (idx, digit) in integer.chars().enumerate() {
+            if self.thousands && idx > 0 && (integer.len() - idx) % 3 == 0 {
+                digits.push(self.locale.thousands_separator());
+            }
+            digits.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            digits.push(self.locale.decimal_separator());
+            digits.push_str(fraction);
+        }
+        digits.push_str(letter);
+
+        // Un valor que redondea a cero no lleva signo
+        let is_zero = fixed.chars().all(|c| c == '0' || c == '.');
+        let sign = match (value < 0.0, self.signed && value > 0.0) {
+            _ if is_zero => "",
+            (true, _) => "-",
+            (_, true) => "+",
+            _ => "",
+        };
+
+        let gap = self.locale.symbol_gap();
+        let number = match &self.unit {
+            NumberUnit::Plain => digits,
+            NumberUnit::Currency(symbol) => format!("{}{}{}", symbol, gap, digits),
+            NumberUnit::Percent => format!("{}{}%", digits, gap),
+            NumberUnit::BasisPoints => format!("{} bps", digits),
+        };
+
+        format!("{}{}{}{}", sign, self.prefix, number, self.suffix)
+    }
+
+    /// Valor en la unidad que se muestra (basis points = % × 100)
+    fn scaled(&self, value: f64) -> f64 {
+        match self.unit {
+            NumberUnit::BasisPoints => value * 100.0,
+            _ => value,
+        }
+    }
+
+    /// Valor absoluto redondeado a `decimals` (con '.' como separador) y la
+    /// letra de la notación compacta
+    fn rounded(&self, value: f64) -> (String, &'static str) {
+        const UNITS: [(f64, &str); 3] = [(1e9, "B"), (1e6, "M"), (1e3, "K")];
+
+        if self.compact {
+            // De la unidad más grande a la más chica; se compara ya
+            // redondeado para que 999.960 sea "1.0M" y no "1000.0K"
+            for (divisor, letter) in UNITS {
+                let fixed = format!("{:.*}", self.decimals, value / divisor);
+                if fixed.parse::<f64>().is_ok_and(|scaled| scaled >= 1.0) {
+                    return (fixed, letter);
+                }
+            }
+            return (format!("{:.0}", value), "");
+        }
+
+        (format!("{:.*}", self.decimals, value), "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_finite_values_show_a_dash() {
+        assert_eq!(NumberFormat::new(2).format(f64::NAN), "—");
+        assert_eq!(NumberFormat::change(2).format(f64::INFINITY), "—");
+        assert_eq!(NumberFormat::currency("$", 2).format(f64::NEG_INFINITY), "—");
+    }
+
+    #[test]
+    fn negative_zero_has_no_sign() {
+        assert_eq!(NumberFormat::new(2).format(-0.0), "0.00");
+        assert_eq!(NumberFormat::change(2).format(-0.0), "0.00%");
+        assert!(NumberFormat::change(2).rounds_to_zero(-0.0));
+    }
+
+    #[test]
+    fn values_rounding_to_zero_never_show_minus_zero() {
+        assert_eq!(NumberFormat::new(2).format(-0.004), "0.00");
+        assert_eq!(NumberFormat::currency("$", 2).format(-0.001), "$0.00");
+        assert_eq!(NumberFormat::bps(0).format(-0.004), "0 bps");
+        assert_eq!(NumberFormat::compact(1).format(-0.4), "0");
+        assert!(NumberFormat::new(2).rounds_to_zero(-0.004));
+        assert!(!NumberFormat::new(2).rounds_to_zero(-0.005001));
+    }
+
+    #[test]
+    fn es_ar_groups_negative_values() {
+        let format = NumberFormat::new(2).with_locale(Locale::EsAr);
+        assert_eq!(format.format(-1_234_567.891), "-1.234.567,89");
+        assert_eq!(format.format(-999.5), "-999,50");
+
+        let currency = NumberFormat::currency("$", 2).with_locale(Locale::EsAr);
+        assert_eq!(currency.format(-1_234.5), "-$ 1.234,50");
+    }
+
+    #[test]
+    fn es_ar_compact_values() {
+        let compact = NumberFormat::compact(1).with_locale(Locale::EsAr);
+        assert_eq!(compact.format(-1_234_567.0), "-1,2M");
+        assert_eq!(compact.format(2_500_000_000.0), "2,5B");
+        assert_eq!(compact.format(-950.0), "-950");
+        assert_eq!(compact.format(-999_960.0), "-1,0M");
+    }
+}
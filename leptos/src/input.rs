@@ -21,7 +21,7 @@ use leptos::ev::Event;
 /// - `disabled`: bool - Si está deshabilitado
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui_leptos::*;
 ///
 /// let (value, set_value) = create_signal(String::new());
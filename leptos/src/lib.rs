@@ -17,6 +17,8 @@ mod modal;
 mod loading;
 mod tooltip;
 mod dropdown;
+mod pagination;
+mod format;
 
 pub use badge::*;
 pub use button::*;
@@ -32,3 +34,5 @@ pub use modal::*;
 pub use loading::*;
 pub use tooltip::*;
 pub use dropdown::*;
+pub use pagination::*;
+pub use format::*;
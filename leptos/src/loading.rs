@@ -18,7 +18,7 @@ use leptos::*;
 /// - `fullscreen`: bool - Si es overlay fullscreen
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui_leptos::*;
 ///
 /// view! {
@@ -22,7 +22,7 @@ use web_sys::{KeyboardEvent, MouseEvent};
 /// - `footer`: Option<View> - Footer custom (default: botón Close)
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui_leptos::*;
 ///
 /// let (show, set_show) = create_signal(false);
@@ -68,7 +68,7 @@ pub fn Modal(
             let document = window.document().unwrap();
             let _ = document.add_event_listener_with_callback(
                 "keydown",
-                wasm_bindgen::closure::Closure::wrap(Box::new(on_keydown.clone()) as Box<dyn Fn(KeyboardEvent)>)
+                wasm_bindgen::closure::Closure::wrap(Box::new(on_keydown) as Box<dyn Fn(KeyboardEvent)>)
                     .as_ref()
                     .unchecked_ref(),
             );
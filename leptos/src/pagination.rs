@@ -0,0 +1,201 @@
+use leptos::*;
+
+use crate::select::{Select, SelectOption};
+
+/// Pagination - Navegación entre páginas de datos
+///
+/// Ver yew/pagination.rs para documentación completa
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use hubermann_ui_leptos::*;
+///
+/// let (page, set_page) = create_signal(1usize);
+/// let (page_size, set_page_size) = create_signal(25usize);
+///
+/// view! {
+///     <Pagination
+///         page=page
+///         set_page=set_page
+///         page_size=page_size
+///         set_page_size=set_page_size
+///         total=1234
+///     />
+/// }
+/// ```
+#[component]
+pub fn Pagination(
+    /// Página actual (empieza en 1)
+    page: ReadSignal<usize>,
+    /// Señal de escritura para la página
+    set_page: WriteSignal<usize>,
+    /// Filas por página
+    page_size: ReadSignal<usize>,
+    /// Señal de escritura para el tamaño (sin ella no se muestra el selector)
+    #[prop(optional)]
+    set_page_size: Option<WriteSignal<usize>>,
+    /// Total de filas
+    #[prop(into)]
+    total: MaybeSignal<usize>,
+    /// Opciones del selector de tamaño de página
+    #[prop(default = default_page_size_options())]
+    page_size_options: Vec<usize>,
+) -> impl IntoView {
+    let total_pages = move || page_count(total.get(), page_size.get());
+    let current = move || page.get().clamp(1, total_pages());
+    let at_first = move || current() <= 1;
+    let at_last = move || current() >= total_pages();
+
+    let range_label = move || {
+        let total = total.get();
+        let size = page_size.get();
+        let first_row = if total == 0 { 0 } else { (current() - 1) * size + 1 };
+        let last_row = (current() * size).min(total);
+        format!("{}–{} of {}", first_row, last_row, total)
+    };
+
+    let nav_class = "px-2 py-1 rounded text-sm text-text-tertiary hover:bg-bg-tertiary hover:text-text-primary transition-colors disabled:opacity-50 disabled:cursor-not-allowed disabled:hover:bg-transparent";
+
+    view! {
+        <div class="flex flex-wrap items-center justify-between gap-3 px-3 py-2">
+            // Rango actual
+            <span class="text-xs text-text-tertiary">
+                {range_label}
+            </span>
+
+            // Páginas
+            <nav aria-label="Pagination" class="flex items-center gap-1">
+                <button
+                    type="button"
+                    aria-label="Previous page"
+                    disabled=at_first
+                    on:click=move |_| set_page.set(current().saturating_sub(1).max(1))
+                    class=nav_class
+                >
+                    "‹"
+                </button>
+
+                {move || pagination_items(current(), total_pages()).into_iter().map(|item| {
+                    match item {
+                        Some(number) if number == current() => view! {
+                            <button
+                                type="button"
+                                aria-current="page"
+                                class="min-w-[2rem] px-2 py-1 rounded text-sm font-medium bg-bg-tertiary text-text-primary border-b-2 border-accent"
+                            >
+                                {number}
+                            </button>
+                        }.into_view(),
+                        Some(number) => view! {
+                            <button
+                                type="button"
+                                on:click=move |_| set_page.set(number)
+                                class="min-w-[2rem] px-2 py-1 rounded text-sm text-text-tertiary hover:bg-bg-tertiary/50 hover:text-text-primary transition-colors"
+                            >
+                                {number}
+                            </button>
+                        }.into_view(),
+                        None => view! {
+                            <span class="px-1 text-sm text-text-muted">"…"</span>
+                        }.into_view(),
+                    }
+                }).collect::<Vec<_>>()}
+
+                <button
+                    type="button"
+                    aria-label="Next page"
+                    disabled=at_last
+                    on:click=move |_| set_page.set((current() + 1).min(total_pages()))
+                    class=nav_class
+                >
+                    "›"
+                </button>
+            </nav>
+
+            // Selector de tamaño de página
+            {set_page_size.map(|set_page_size| {
+                let (size_value, set_size_value) = create_signal(page_size.get_untracked().to_string());
+
+                // Select → page_size (volviendo a la primera página)
+                create_effect(move |_| {
+                    if let Ok(size) = size_value.get().parse::<usize>() {
+                        if size != page_size.get_untracked() {
+                            set_page_size.set(size);
+                            set_page.set(1);
+                        }
+                    }
+                });
+
+                // page_size externo → Select
+                create_effect(move |_| set_size_value.set(page_size.get().to_string()));
+
+                let options = page_size_options
+                    .iter()
+                    .map(|size| SelectOption::new(size.to_string(), size.to_string()))
+                    .collect::<Vec<_>>();
+
+                view! {
+                    <div class="flex items-center gap-2">
+                        <span class="text-xs text-text-tertiary">"Rows"</span>
+                        <div class="w-20">
+                            <Select
+                                options=options
+                                value=size_value
+                                set_value=set_size_value
+                            />
+                        </div>
+                    </div>
+                }
+            })}
+        </div>
+    }
+}
+
+pub(crate) fn default_page_size_options() -> Vec<usize> {
+    vec![10, 25, 50, 100]
+}
+
+/// Cantidad de páginas (mínimo 1, aunque no haya filas)
+pub fn page_count(total: usize, page_size: usize) -> usize {
+    if page_size == 0 {
+        return 1;
+    }
+    total.div_ceil(page_size).max(1)
+}
+
+/// Números de página a mostrar; `None` representa una elipsis
+///
+/// ```
+/// use hubermann_ui_leptos::pagination_items;
+///
+/// assert_eq!(
+///     pagination_items(10, 20),
+///     vec![Some(1), None, Some(9), Some(10), Some(11), None, Some(20)]
+/// );
+/// ```
+pub fn pagination_items(page: usize, total_pages: usize) -> Vec<Option<usize>> {
+    if total_pages <= 7 {
+        return (1..=total_pages).map(Some).collect();
+    }
+
+    let page = page.clamp(1, total_pages);
+    // Ventana de páginas vecinas entre la primera y la última
+    let (start, end) = if page <= 4 {
+        (2, 5)
+    } else if page >= total_pages - 3 {
+        (total_pages - 4, total_pages - 1)
+    } else {
+        (page - 1, page + 1)
+    };
+
+    let mut items = vec![Some(1)];
+    if start > 2 {
+        items.push(None);
+    }
+    items.extend((start..=end).map(Some));
+    if end < total_pages - 1 {
+        items.push(None);
+    }
+    items.push(Some(total_pages));
+    items
+}
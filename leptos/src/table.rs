@@ -1,8 +1,41 @@
+use std::cmp::Ordering;
+use std::rc::Rc;
 use leptos::*;
 
+use crate::accordion::accordion_chevron;
+use crate::format::NumberFormat;
+use crate::pagination::{default_page_size_options, page_count, Pagination};
+
 /// Table - Tabla de datos con sorting opcional
 ///
 /// Ver yew/table.rs para documentación completa
+///
+/// En modo controlado se pasa `sort` como `RwSignal`: la tabla lee el
+/// orden desde ahí y escribe cada cambio. Sin `sort` maneja su propio
+/// estado arrancando en `default_sort`.
+///
+/// Con `pagination` se muestra un `Pagination` debajo de la tabla. La página
+/// y el tamaño son controlados si se pasan `page` / `page_size`; en modo
+/// server-side el parent observa esas señales para hacer el fetch.
+///
+/// Con `row_detail` cada fila tiene un chevron para mostrar su detalle
+/// debajo (ArrowRight expande, ArrowLeft colapsa). Las filas expandidas se
+/// identifican por su índice en `rows`; son controladas si se pasa
+/// `expanded`.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// let expanded = create_rw_signal(Vec::<usize>::new());
+///
+/// view! {
+///     <Table
+///         headers=headers
+///         rows=rows
+///         row_detail=move |idx: usize| view! { <TradeFills trade=trades[idx].clone() /> }.into_view()
+///         expanded=expanded
+///     />
+/// }
+/// ```
 #[component]
 pub fn Table(
     /// Headers de las columnas
@@ -12,7 +45,47 @@ pub fn Table(
     /// Si tiene efecto hover
     #[prop(default = true)]
     hoverable: bool,
+    /// Headers clickeables que ciclan asc/desc/sin orden
+    #[prop(default = false)]
+    sortable: bool,
+    /// Orden actual (modo controlado)
+    #[prop(optional)]
+    sort: Option<RwSignal<Option<TableSort>>>,
+    /// Orden inicial (modo no controlado)
+    #[prop(optional)]
+    default_sort: Option<TableSort>,
+    /// Paginación client-side o server-side
+    #[prop(optional)]
+    pagination: Option<TablePagination>,
+    /// Página actual, empieza en 1 (modo controlado)
+    #[prop(optional)]
+    page: Option<RwSignal<usize>>,
+    /// Filas por página (modo controlado)
+    #[prop(optional)]
+    page_size: Option<RwSignal<usize>>,
+    /// Contenido expandible debajo de cada fila (recibe el índice en `rows`)
+    #[prop(optional, into)]
+    row_detail: Option<Callback<usize, View>>,
+    /// Índices de filas expandidas (modo controlado)
+    #[prop(optional)]
+    expanded: Option<RwSignal<Vec<usize>>>,
 ) -> impl IntoView {
+    let sort = sort.unwrap_or_else(|| create_rw_signal(default_sort));
+    let row_count = rows.len();
+    let rows = store_value(rows);
+
+    let page = page.unwrap_or_else(|| create_rw_signal(1));
+    let page_size = page_size.unwrap_or_else(|| {
+        create_rw_signal(pagination.as_ref().map(|p| p.page_size).unwrap_or(0))
+    });
+    let paginated = pagination.is_some();
+    // En client-side la tabla corta la página; en server-side rows ya es la página
+    let client_side = pagination.as_ref().is_some_and(|p| p.total.is_none());
+
+    let expandable = row_detail.is_some();
+    let expanded = expanded.unwrap_or_else(|| create_rw_signal(Vec::new()));
+    let column_count = headers.len() + usize::from(expandable);
+
     let hover_class = if hoverable {
         "hover:bg-bg-tertiary transition-colors"
     } else {
@@ -25,39 +98,217 @@ pub fn Table(
                 <table class="w-full text-sm">
                     <thead class="bg-bg-tertiary border-b border-border-default">
                         <tr>
-                            {headers.into_iter().map(|header| view! {
-                                <th class="px-3 py-3 text-left font-semibold text-text-primary">
-                                    {header}
-                                </th>
+                            // Columna de expansión
+                            {expandable.then(|| view! {
+                                <th class="w-10"><span class="sr-only">"Details"</span></th>
+                            })}
+                            {headers.into_iter().enumerate().map(|(column, header)| {
+                                if !sortable {
+                                    return view! {
+                                        <th class="px-3 py-3 text-left font-semibold text-text-primary">
+                                            {header}
+                                        </th>
+                                    };
+                                }
+
+                                let direction = move || {
+                                    sort.get()
+                                        .filter(|s| s.column == column)
+                                        .map(|s| s.direction)
+                                };
+
+                                let aria_sort = move || match direction() {
+                                    Some(SortDirection::Ascending) => "ascending",
+                                    Some(SortDirection::Descending) => "descending",
+                                    None => "none",
+                                };
+
+                                let indicator = move || match direction() {
+                                    Some(SortDirection::Ascending) => "↑",
+                                    Some(SortDirection::Descending) => "↓",
+                                    None => "↕",
+                                };
+
+                                let indicator_class = move || match direction() {
+                                    Some(_) => "text-xs text-accent",
+                                    None => "text-xs text-text-muted",
+                                };
+
+                                view! {
+                                    <th
+                                        aria-sort=aria_sort
+                                        class="px-3 py-3 text-left font-semibold text-text-primary"
+                                    >
+                                        <button
+                                            type="button"
+                                            on:click=move |_| {
+                                                sort.update(|s| *s = TableSort::next(s.as_ref(), column));
+                                                // Al reordenar se vuelve a la primera página
+                                                if paginated {
+                                                    page.set(1);
+                                                }
+                                            }
+                                            class="inline-flex items-center gap-1 hover:text-accent transition-colors"
+                                        >
+                                            {header}
+                                            <span class=indicator_class>{indicator}</span>
+                                        </button>
+                                    </th>
+                                }
                             }).collect::<Vec<_>>()}
                         </tr>
                     </thead>
 
                     <tbody>
-                        {rows.into_iter().enumerate().map(|(idx, row)| {
-                            let border_class = if idx < row.cells.len() - 1 {
-                                "border-b border-border-subtle"
+                        {move || {
+                            let order: Vec<usize> = rows.with_value(|rows| {
+                                if sortable {
+                                    sorted_indices(rows, sort.get().as_ref())
+                                } else {
+                                    (0..rows.len()).collect()
+                                }
+                            });
+
+                            let order = if client_side {
+                                let size = page_size.get();
+                                let current = page.get().clamp(1, page_count(row_count, size));
+                                let start = ((current - 1) * size).min(row_count);
+                                let end = (start + size).min(row_count);
+                                order[start..end].to_vec()
                             } else {
-                                ""
+                                order
                             };
+                            let visible_count = order.len();
+                            let open_rows = expanded.get();
+
+                            order.into_iter().enumerate().map(|(idx, row_idx)| {
+                                let row = rows.with_value(|rows| rows[row_idx].clone());
+                                let is_expanded = open_rows.contains(&row_idx);
+                                let border_class = if idx < visible_count - 1 || is_expanded {
+                                    "border-b border-border-subtle"
+                                } else {
+                                    ""
+                                };
 
-                            view! {
-                                <tr class={format!("{} {}", border_class, hover_class)}>
-                                    {row.cells.into_iter().map(|cell| view! {
-                                        <td class="px-3 py-3">
-                                            {render_cell(cell)}
+                                let set_open = move |open: bool| {
+                                    expanded.update(|rows| {
+                                        rows.retain(|&r| r != row_idx);
+                                        if open {
+                                            rows.push(row_idx);
+                                        }
+                                    });
+                                };
+
+                                let detail = row_detail.filter(|_| is_expanded).map(|detail| view! {
+                                    <tr class="bg-bg-primary/40">
+                                        <td
+                                            id=format!("detail-{}", row_idx)
+                                            colspan=column_count.to_string()
+                                            class="px-3 py-3"
+                                        >
+                                            {detail.call(row_idx)}
                                         </td>
-                                    }).collect::<Vec<_>>()}
-                                </tr>
-                            }
-                        }).collect::<Vec<_>>()}
+                                    </tr>
+                                });
+
+                                view! {
+                                    <tr class={format!("{} {}", border_class, hover_class)}>
+                                        {expandable.then(|| view! {
+                                            <td class="w-10 px-3 py-3">
+                                                <button
+                                                    type="button"
+                                                    aria-label=if is_expanded { "Collapse row" } else { "Expand row" }
+                                                    aria-expanded=is_expanded.to_string()
+                                                    aria-controls=is_expanded.then(|| format!("detail-{}", row_idx))
+                                                    on:click=move |_| set_open(!is_expanded)
+                                                    on:keydown=move |e| match e.key().as_str() {
+                                                        "ArrowRight" => {
+                                                            e.prevent_default();
+                                                            set_open(true);
+                                                        }
+                                                        "ArrowLeft" => {
+                                                            e.prevent_default();
+                                                            set_open(false);
+                                                        }
+                                                        _ => {}
+                                                    }
+                                                    class="flex items-center rounded hover:bg-bg-tertiary transition-colors"
+                                                >
+                                                    {accordion_chevron(is_expanded, "w-4 h-4")}
+                                                </button>
+                                            </td>
+                                        })}
+                                        {row.cells.into_iter().map(|cell| view! {
+                                            <td class="px-3 py-3">
+                                                {render_cell(cell)}
+                                            </td>
+                                        }).collect::<Vec<_>>()}
+                                    </tr>
+                                    {detail}
+                                }
+                            }).collect::<Vec<_>>()
+                        }}
                     </tbody>
                 </table>
             </div>
+
+            // Paginación
+            {pagination.map(|pagination| {
+                let total = pagination.total.unwrap_or(row_count);
+                view! {
+                    <div class="border-t border-border-default">
+                        <Pagination
+                            page=page.read_only()
+                            set_page=page.write_only()
+                            page_size=page_size.read_only()
+                            set_page_size=page_size.write_only()
+                            total=total
+                            page_size_options=pagination.page_size_options
+                        />
+                    </div>
+                }
+            })}
         </div>
     }
 }
 
+/// Configuración de paginación de la tabla
+///
+/// - `TablePagination::client`: la tabla recibe todas las filas y muestra la página actual
+/// - `TablePagination::server`: `rows` ya es la página actual y `total` el conteo completo
+#[derive(Clone, PartialEq, Debug)]
+pub struct TablePagination {
+    /// Filas por página inicial (modo no controlado)
+    pub page_size: usize,
+    /// Opciones del selector de tamaño de página
+    pub page_size_options: Vec<usize>,
+    /// Total de filas en el servidor (None = client-side)
+    pub total: Option<usize>,
+}
+
+impl TablePagination {
+    pub fn client(page_size: usize) -> Self {
+        Self {
+            page_size,
+            page_size_options: default_page_size_options(),
+            total: None,
+        }
+    }
+
+    pub fn server(total: usize, page_size: usize) -> Self {
+        Self {
+            page_size,
+            page_size_options: default_page_size_options(),
+            total: Some(total),
+        }
+    }
+
+    pub fn with_page_size_options(mut self, options: Vec<usize>) -> Self {
+        self.page_size_options = options;
+        self
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub struct TableRow {
     pub cells: Vec<TableCell>,
@@ -73,6 +324,8 @@ impl TableRow {
 pub struct TableCell {
     pub content: String,
     pub cell_type: TableCellType,
+    /// Clave de orden explícita (si es None se deriva del content)
+    pub sort_key: Option<SortKey>,
 }
 
 #[derive(Clone, PartialEq)]
@@ -81,6 +334,39 @@ pub enum TableCellType {
     Primary,
     Secondary,
     Change(TableChangeType),
+    /// Número alineado a la derecha con cifras tabulares, opcionalmente
+    /// alineado en el separador decimal de su `Locale`
+    Numeric {
+        decimal_align: Option<usize>,
+        decimal_separator: char,
+    },
+    /// Contenido custom (Badge, Button, Tooltip, ...)
+    Custom(TableCellView),
+}
+
+/// Contenido de una celda custom
+///
+/// Equivalente a `Custom(Html)` de Yew: un closure que crea la `View` cada
+/// vez que se dibuja la fila (`TableCell::custom_with`). Una `View` de
+/// Leptos es un nodo del DOM, así que no se puede compartir entre filas ni
+/// entre renders. Se compara por identidad, igual que `Callback`.
+#[derive(Clone)]
+pub struct TableCellView(Rc<dyn Fn() -> View>);
+
+impl TableCellView {
+    pub fn new<V: IntoView>(render: impl Fn() -> V + 'static) -> Self {
+        Self(Rc::new(move || render().into_view()))
+    }
+
+    pub fn render(&self) -> View {
+        (self.0)()
+    }
+}
+
+impl PartialEq for TableCellView {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 #[derive(Clone, PartialEq, Copy)]
@@ -95,6 +381,7 @@ impl TableCell {
         Self {
             content: content.into(),
             cell_type: TableCellType::Text,
+            sort_key: None,
         }
     }
 
@@ -102,6 +389,7 @@ impl TableCell {
         Self {
             content: content.into(),
             cell_type: TableCellType::Primary,
+            sort_key: None,
         }
     }
 
@@ -109,6 +397,7 @@ impl TableCell {
         Self {
             content: content.into(),
             cell_type: TableCellType::Secondary,
+            sort_key: None,
         }
     }
 
@@ -116,10 +405,224 @@ impl TableCell {
         Self {
             content: content.into(),
             cell_type: TableCellType::Change(change_type),
+            sort_key: None,
+        }
+    }
+
+    /// Celda custom renderizada por un closure (Badge, Button, Tooltip, ...)
+    ///
+    /// El closure se llama en cada render de la fila, así cada fila y cada
+    /// reordenamiento o cambio de página tiene su propia `View`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// TableCell::custom_with(move || view! {
+    ///     <Badge variant=BadgeVariant::Bullish>"Filled"</Badge>
+    /// })
+    /// ```
+    pub fn custom_with<V: IntoView>(render: impl Fn() -> V + 'static) -> Self {
+        Self {
+            content: String::new(),
+            cell_type: TableCellType::Custom(TableCellView::new(render)),
+            sort_key: None,
+        }
+    }
+
+    /// Celda custom a partir de una `View` ya creada
+    ///
+    /// La misma `View` (los mismos nodos del DOM) se muestra en cada render:
+    /// la celda no se puede reusar entre filas y la tabla no se puede
+    /// reordenar ni paginar. Para eso usar `custom_with`.
+    pub fn custom(view: impl IntoView) -> Self {
+        let view = view.into_view();
+        Self::custom_with(move || view.clone())
+    }
+
+    /// Celda numérica con el valor crudo como clave de orden
+    pub fn numeric(value: f64, format: &NumberFormat) -> Self {
+        Self {
+            content: format.format(value),
+            cell_type: TableCellType::Numeric {
+                decimal_align: None,
+                decimal_separator: format.locale.decimal_separator(),
+            },
+            sort_key: Some(SortKey::Number(value)),
+        }
+    }
+
+    /// Alinea una celda numérica en el punto decimal
+    pub fn with_decimal_align(mut self, width: usize) -> Self {
+        if let TableCellType::Numeric { decimal_align, .. } = &mut self.cell_type {
+            *decimal_align = Some(width);
+        }
+        self
+    }
+
+    /// Parte entera y parte decimal (desde el separador) de una celda numérica
+    ///
+    /// ```
+    /// use hubermann_ui_leptos::{Locale, NumberFormat, TableCell};
+    ///
+    /// let cell = TableCell::numeric(1234.56, &NumberFormat::new(2).with_locale(Locale::EsAr));
+    /// assert_eq!(cell.decimal_parts(), ("1.234", ",56"));
+    /// ```
+    pub fn decimal_parts(&self) -> (&str, &str) {
+        let separator = match self.cell_type {
+            TableCellType::Numeric { decimal_separator, .. } => decimal_separator,
+            _ => '.',
+        };
+        match self.content.find(separator) {
+            Some(point) => self.content.split_at(point),
+            None => (self.content.as_str(), ""),
+        }
+    }
+
+    /// Fija la clave de orden de la celda
+    pub fn with_sort_key(mut self, sort_key: SortKey) -> Self {
+        self.sort_key = Some(sort_key);
+        self
+    }
+
+    /// Clave de orden efectiva: la explícita o la derivada del content.
+    /// Las celdas Custom sin clave explícita quedan siempre al final.
+    pub fn sort_key(&self) -> SortKey {
+        match (&self.sort_key, &self.cell_type) {
+            (Some(key), _) => key.clone(),
+            (None, TableCellType::Custom(_)) => SortKey::Empty,
+            (None, _) => SortKey::parse(&self.content),
         }
     }
 }
 
+/// Clave de orden de una celda
+///
+/// Ver yew/table.rs para documentación completa
+#[derive(Clone, PartialEq, Debug)]
+pub enum SortKey {
+    Number(f64),
+    Text(String),
+    Empty,
+}
+
+impl SortKey {
+    /// Deriva una clave desde texto formateado ("$1,182.45", "+2.3%", "45.2M")
+    ///
+    /// ```
+    /// use hubermann_ui_leptos::SortKey;
+    ///
+    /// assert_eq!(SortKey::parse("+2.3%"), SortKey::Number(2.3));
+    /// assert_eq!(SortKey::parse("45.2M"), SortKey::Number(45_200_000.0));
+    /// assert_eq!(SortKey::parse("AAPL"), SortKey::Text("AAPL".to_string()));
+    /// ```
+    pub fn parse(content: &str) -> Self {
+        let trimmed = content.trim();
+        if trimmed.is_empty() {
+            return SortKey::Empty;
+        }
+
+        let cleaned: String = trimmed
+            .replace('\u{2212}', "-")
+            .chars()
+            .filter(|c| !matches!(c, '$' | '€' | '£' | ',' | '+' | '%' | ' '))
+            .collect();
+
+        let (number, multiplier) = match cleaned.chars().last() {
+            Some('K') | Some('k') => (&cleaned[..cleaned.len() - 1], 1e3),
+            Some('M') => (&cleaned[..cleaned.len() - 1], 1e6),
+            Some('B') => (&cleaned[..cleaned.len() - 1], 1e9),
+            _ => (cleaned.as_str(), 1.0),
+        };
+
+        match number.parse::<f64>() {
+            Ok(value) if value.is_finite() => SortKey::Number(value * multiplier),
+            _ => SortKey::Text(trimmed.to_string()),
+        }
+    }
+
+    /// Compara dos claves en orden ascendente
+    pub fn compare(&self, other: &SortKey) -> Ordering {
+        match (self, other) {
+            (SortKey::Number(a), SortKey::Number(b)) => a.total_cmp(b),
+            (SortKey::Text(a), SortKey::Text(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+            (SortKey::Number(_), SortKey::Text(_)) => Ordering::Less,
+            (SortKey::Text(_), SortKey::Number(_)) => Ordering::Greater,
+            (SortKey::Empty, SortKey::Empty) => Ordering::Equal,
+            (SortKey::Empty, _) => Ordering::Greater,
+            (_, SortKey::Empty) => Ordering::Less,
+        }
+    }
+}
+
+/// Dirección de orden de una columna
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// Estado de orden de la tabla: qué columna y en qué dirección
+#[derive(Clone, PartialEq, Debug)]
+pub struct TableSort {
+    pub column: usize,
+    pub direction: SortDirection,
+}
+
+impl TableSort {
+    pub fn asc(column: usize) -> Self {
+        Self {
+            column,
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    pub fn desc(column: usize) -> Self {
+        Self {
+            column,
+            direction: SortDirection::Descending,
+        }
+    }
+
+    /// Próximo estado al clickear un header: asc → desc → sin orden
+    pub fn next(current: Option<&TableSort>, column: usize) -> Option<TableSort> {
+        match current {
+            Some(sort) if sort.column == column => match sort.direction {
+                SortDirection::Ascending => Some(TableSort::desc(column)),
+                SortDirection::Descending => None,
+            },
+            _ => Some(TableSort::asc(column)),
+        }
+    }
+}
+
+/// Índices de `rows` en el orden a mostrar (sort estable)
+fn sorted_indices(rows: &[TableRow], sort: Option<&TableSort>) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..rows.len()).collect();
+
+    if let Some(sort) = sort {
+        let keys: Vec<SortKey> = rows
+            .iter()
+            .map(|row| {
+                row.cells
+                    .get(sort.column)
+                    .map(TableCell::sort_key)
+                    .unwrap_or(SortKey::Empty)
+            })
+            .collect();
+
+        indices.sort_by(|&a, &b| {
+            let (a, b) = (&keys[a], &keys[b]);
+            match (a, b, sort.direction) {
+                // Vacíos al final en ambas direcciones
+                (SortKey::Empty, _, _) | (_, SortKey::Empty, _) => a.compare(b),
+                (_, _, SortDirection::Ascending) => a.compare(b),
+                (_, _, SortDirection::Descending) => b.compare(a),
+            }
+        });
+    }
+
+    indices
+}
+
 fn render_cell(cell: TableCell) -> View {
     match cell.cell_type {
         TableCellType::Text => {
@@ -148,5 +651,24 @@ fn render_cell(cell: TableCell) -> View {
                 <span class={color_class}>{cell.content}</span>
             }.into_view()
         }
+        TableCellType::Numeric { decimal_align, .. } => {
+            let content = match decimal_align {
+                Some(width) => {
+                    let (integer, fraction) = cell.decimal_parts();
+                    view! {
+                        {integer.to_string()}
+                        <span class="inline-block text-left" style=format!("min-width: {}ch", width)>
+                            {fraction.to_string()}
+                        </span>
+                    }.into_view()
+                }
+                None => cell.content.into_view(),
+            };
+
+            view! {
+                <span class="block text-right font-mono tabular-nums text-text-secondary">{content}</span>
+            }.into_view()
+        }
+        TableCellType::Custom(view) => view.render(),
     }
 }
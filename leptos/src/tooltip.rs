@@ -18,7 +18,7 @@ use leptos::*;
 /// - `rich`: bool - Si permite contenido multi-línea
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui_leptos::*;
 ///
 /// view! {
@@ -114,6 +114,34 @@ module.exports = {
         slow: visualLanguage.transitions.slow,
       },
       
+      // === ANIMATIONS ===
+      // Flash de celdas en vivo (Table flash_changes) y scroll del TickerTape
+      keyframes: {
+        'flash-bullish': {
+          '0%': { backgroundColor: `${theme.colors.semantic.bullish.DEFAULT}40` },
+          '100%': { backgroundColor: 'transparent' },
+        },
+        'flash-bearish': {
+          '0%': { backgroundColor: `${theme.colors.semantic.bearish.DEFAULT}40` },
+          '100%': { backgroundColor: 'transparent' },
+        },
+        'flash-neutral': {
+          '0%': { backgroundColor: `${theme.colors.semantic.neutral.DEFAULT}40` },
+          '100%': { backgroundColor: 'transparent' },
+        },
+        // El contenido va duplicado: -50% deja la segunda copia donde empezó la primera
+        'ticker': {
+          '0%': { transform: 'translateX(0)' },
+          '100%': { transform: 'translateX(-50%)' },
+        },
+      },
+      animation: {
+        'flash-bullish': 'flash-bullish 1s ease-out',
+        'flash-bearish': 'flash-bearish 1s ease-out',
+        'flash-neutral': 'flash-neutral 1s ease-out',
+        'ticker': 'ticker 40s linear infinite',
+      },
+      
       // === FONTS ===
       fontFamily: {
         sans: ['Inter', 'system-ui', '-apple-system', 'sans-serif'],
@@ -22,7 +22,7 @@ use yew::prelude::*;
 /// - `default_open`: bool - Si empieza abierto (default: false)
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// html! {
@@ -63,13 +63,6 @@ pub fn accordion(props: &AccordionProps) -> Html {
         })
     };
     
-    // Classes dinámicas según estado
-    let chevron_class = if *open {
-        "w-5 h-5 text-text-tertiary transition-transform rotate-180"
-    } else {
-        "w-5 h-5 text-text-tertiary transition-transform"
-    };
-    
     html! {
         <div class="border border-border-default rounded-md bg-bg-secondary">
             // Summary - siempre visible
@@ -97,19 +90,7 @@ pub fn accordion(props: &AccordionProps) -> Html {
                 }
 
                 // Right: Chevron
-                <svg 
-                    class={chevron_class}
-                    fill="none" 
-                    stroke="currentColor" 
-                    viewBox="0 0 24 24"
-                >
-                    <path 
-                        stroke-linecap="round" 
-                        stroke-linejoin="round" 
-                        stroke-width="2" 
-                        d="M19 9l-7 7-7-7" 
-                    />
-                </svg>
+                {accordion_chevron(*open, "w-5 h-5")}
             </button>
 
             // Content - solo visible cuando open
@@ -121,3 +102,27 @@ pub fn accordion(props: &AccordionProps) -> Html {
         </div>
     }
 }
+
+/// Chevron del Accordion (apunta arriba cuando está abierto)
+///
+/// Compartido con los grupos y filas expandibles de Table.
+pub(crate) fn accordion_chevron(open: bool, size_class: &'static str) -> Html {
+    // Classes dinámicas según estado
+    let rotate_class = open.then_some("rotate-180");
+
+    html! {
+        <svg 
+            class={classes!(size_class, "text-text-tertiary", "transition-transform", rotate_class)}
+            fill="none" 
+            stroke="currentColor" 
+            viewBox="0 0 24 24"
+        >
+            <path 
+                stroke-linecap="round" 
+                stroke-linejoin="round" 
+                stroke-width="2" 
+                d="M19 9l-7 7-7-7" 
+            />
+        </svg>
+    }
+}
@@ -17,7 +17,7 @@ use yew::prelude::*;
 /// - `text`: String - Contenido textual
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// html! {
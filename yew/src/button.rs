@@ -19,7 +19,7 @@ use yew::prelude::*;
 /// - `children`: Children - Contenido (texto/iconos)
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// html! {
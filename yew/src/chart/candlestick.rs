@@ -0,0 +1,420 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::line::{LineSeries, SeriesColor};
+use super::range::{min_span, use_chart_range, use_chart_zoom, ChartBrush, ChartRange};
+use super::scale::{format_timestamp, nice_ticks, tick_decimals, time_ticks, LinearScale};
+use super::{
+    axis_marker, chart_tooltip, horizontal_grid, use_chart_pointer, use_element_width, y_axis_labels,
+    AXIS_HEIGHT, AXIS_WIDTH, PLOT_TOP,
+};
+use crate::format::NumberFormat;
+use crate::stats_card::ChangeType;
+
+/// Proporción del alto que ocupa el panel de volumen
+const VOLUME_RATIO: f64 = 0.2;
+/// Separación entre el panel de precio y el de volumen
+const PANEL_GAP: f64 = 8.0;
+
+/// Vela OHLCV; `time` es un timestamp unix en segundos (UTC)
+///
+/// ```
+/// use hubermann_ui::Candle;
+///
+/// let candle = Candle::new(1_704_067_200, 42_150.0, 42_890.5, 41_980.0, 42_610.2, 1_284.6);
+/// assert!(candle.is_bullish());
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Candle {
+    pub time: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+impl Candle {
+    pub fn new(time: i64, open: f64, high: f64, low: f64, close: f64, volume: f64) -> Self {
+        Self {
+            time,
+            open,
+            high,
+            low,
+            close,
+            volume,
+        }
+    }
+
+    /// Cierre mayor o igual a la apertura (un doji se dibuja como bullish)
+    pub fn is_bullish(&self) -> bool {
+        self.close >= self.open
+    }
+
+    fn fill_class(&self) -> &'static str {
+        if self.is_bullish() {
+            "fill-bullish stroke-bullish"
+        } else {
+            "fill-bearish stroke-bearish"
+        }
+    }
+}
+
+/// CandlestickChart - Gráfico de velas OHLC en SVG
+///
+/// Velas en colores bullish/bearish del theme, panel de volumen opcional,
+/// eje de precios a la derecha y eje de tiempo abajo con ticks redondos
+/// (ver `nice_ticks` y `time_ticks`). Al pasar el mouse muestra un
+/// crosshair con el precio en el eje y un tooltip con el OHLCV de la vela.
+///
+/// `overlays` dibuja series sobre las velas (medias móviles, Bollinger,
+/// VWAP; ver `indicator_series`), alineadas por timestamp; sus valores
+/// también aparecen en el tooltip.
+///
+/// El chart ocupa todo el ancho del contenedor y se redibuja al
+/// redimensionar la ventana.
+///
+/// # Zoom y rango visible
+/// Con `zoomable` la rueda hace zoom alrededor del mouse, arrastrar hace
+/// pan y doble click vuelve a mostrar todo. Con `brush` se agrega debajo
+/// una vista general de toda la serie donde se arrastra la ventana visible
+/// o sus bordes. El rango es un `ChartRange` de timestamps: si se pasa
+/// `range` queda controlado por el parent (ej: vinculado a `Tabs` de
+/// timeframe) y cada cambio se emite por `onrangechange`.
+///
+/// Respeta el visual language:
+/// - Grid en border-subtle, labels xs en text-tertiary
+/// - Colores semánticos sólidos, volumen al 40%
+/// - Crosshair punteado, tooltip en bg-elevated con border sutil
+/// - Números en font-mono con tabular-nums
+/// - Sin animaciones
+///
+/// # Props
+/// - `candles`: Vec<Candle> - Velas en orden temporal
+/// - `height`: f64 - Alto total en px (default: 360)
+/// - `show_volume`: bool - Panel de volumen debajo del precio (default: true)
+/// - `decimals`: usize - Decimales de precios en tooltip y crosshair (default: 2)
+/// - `overlays`: Vec<LineSeries> - Series superpuestas en el panel de precio
+/// - `label`: Option<String> - Descripción para lectores de pantalla
+/// - `zoomable`: bool - Zoom con la rueda, pan y doble click para resetear (default: false)
+/// - `brush`: bool - Vista general con la ventana visible debajo (default: false)
+/// - `range`: Option<ChartRange> - Rango visible controlado
+/// - `onrangechange`: Option<Callback<ChartRange>> - Cambios de rango (zoom, pan, brush)
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use hubermann_ui::*;
+///
+/// html! {
+///     <CandlestickChart
+///         candles={vec![
+///             Candle::new(1_704_067_200, 42_150.0, 42_890.5, 41_980.0, 42_610.2, 1_284.6),
+///             Candle::new(1_704_070_800, 42_610.2, 42_700.0, 42_020.3, 42_105.9, 986.1),
+///         ]}
+///         height={420.0}
+///     />
+/// }
+/// ```
+#[derive(Properties, PartialEq)]
+pub struct CandlestickChartProps {
+    pub candles: Vec<Candle>,
+    #[prop_or(360.0)]
+    pub height: f64,
+    #[prop_or(true)]
+    pub show_volume: bool,
+    #[prop_or(2)]
+    pub decimals: usize,
+    #[prop_or_default]
+    pub overlays: Vec<LineSeries>,
+    #[prop_or_default]
+    pub label: Option<String>,
+    #[prop_or(false)]
+    pub zoomable: bool,
+    #[prop_or(false)]
+    pub brush: bool,
+    #[prop_or_default]
+    pub range: Option<ChartRange>,
+    #[prop_or_default]
+    pub onrangechange: Option<Callback<ChartRange>>,
+}
+
+#[function_component(CandlestickChart)]
+pub fn candlestick_chart(props: &CandlestickChartProps) -> Html {
+    let container_ref = use_node_ref();
+    let svg_ref = use_node_ref();
+    let width = use_element_width(container_ref.clone(), 640.0);
+    let (pointer, onmousemove, onmouseleave) = use_chart_pointer(svg_ref.clone());
+
+    let plot_right = (width - AXIS_WIDTH).max(1.0);
+    let bounds = ChartRange::of(props.candles.iter().map(|c| c.time));
+    let min_span = bounds.map_or(0, |bounds| min_span(bounds, props.candles.len()));
+    let range_state = use_chart_range(bounds, min_span, props.range, props.onrangechange.clone());
+    let zoom = use_chart_zoom(svg_ref.clone(), props.zoomable, plot_right, range_state.clone());
+    let range = range_state.current;
+
+    // Velas dentro del rango visible (vienen en orden temporal)
+    let candles = match range {
+        Some(range) => {
+            let first = props.candles.partition_point(|c| c.time < range.start);
+            let last = props.candles.partition_point(|c| c.time <= range.end);
+            &props.candles[first..last.max(first)]
+        }
+        None => &props.candles[..],
+    };
+    let height = props.height;
+
+    if candles.is_empty() {
+        return html! {
+            <div
+                ref={container_ref}
+                class="flex items-center justify-center w-full text-sm text-text-tertiary"
+                style={format!("height: {}px", height)}
+            >
+                {"No data"}
+            </div>
+        };
+    }
+
+    // Paneles: precio arriba, volumen abajo (opcional), eje de tiempo al pie
+    let plot_bottom = height - AXIS_HEIGHT;
+    let volume_height = if props.show_volume {
+        (plot_bottom - PLOT_TOP) * VOLUME_RATIO
+    } else {
+        0.0
+    };
+    let price_bottom = if props.show_volume {
+        plot_bottom - volume_height - PANEL_GAP
+    } else {
+        plot_bottom
+    };
+
+    // Overlays: cada punto se ubica en la vela con su mismo timestamp
+    let times: Vec<i64> = candles.iter().map(|c| c.time).collect();
+    let overlays: Vec<_> = props
+        .overlays
+        .iter()
+        .enumerate()
+        .map(|(idx, series)| {
+            let points: Vec<(usize, f64)> = series
+                .points
+                .iter()
+                .filter_map(|(time, value)| times.binary_search(time).ok().map(|idx| (idx, *value)))
+                .collect();
+            (series, SeriesColor::of(series, idx), points)
+        })
+        .collect();
+    let overlay_values = overlays.iter().flat_map(|(_, _, points)| points.iter().map(|(_, value)| *value));
+
+    let low = candles.iter().map(|c| c.low).chain(overlay_values.clone()).fold(f64::INFINITY, f64::min);
+    let high = candles.iter().map(|c| c.high).chain(overlay_values).fold(f64::NEG_INFINITY, f64::max);
+    let padding = match high - low {
+        range if range > 0.0 => range * 0.05,
+        _ => high.abs() * 0.01 + 1.0,
+    };
+    let price = LinearScale::new((low - padding, high + padding), (price_bottom, PLOT_TOP));
+    let max_volume = candles.iter().map(|c| c.volume).fold(0.0, f64::max);
+    let volume = LinearScale::new((0.0, max_volume), (plot_bottom, plot_bottom - volume_height));
+
+    // Cada vela ocupa un slot; el cuerpo usa el 70% dejando aire entre velas
+    let slot = plot_right / candles.len() as f64;
+    let body_width = (slot * 0.7).max(1.0);
+    let center = |idx: usize| slot * (idx as f64 + 0.5);
+
+    let price_ticks = nice_ticks(low - padding, high + padding, ((price_bottom - PLOT_TOP) / 48.0).max(2.0) as usize);
+    let axis_decimals = match price_ticks.as_slice() {
+        [a, b, ..] => tick_decimals(b - a),
+        _ => props.decimals,
+    };
+    let x_ticks = time_ticks(&times, (plot_right / 96.0).max(2.0) as usize);
+
+    let grid = html! {
+        <g class="stroke-border-subtle" stroke-width="1">
+            {horizontal_grid(&price_ticks, &price, plot_right)}
+            { for x_ticks.iter().map(|(idx, _)| {
+                let x = center(*idx).round() + 0.5;
+                html! { <line x1={x.to_string()} y1={PLOT_TOP.to_string()} x2={x.to_string()} y2={plot_bottom.to_string()} /> }
+            }) }
+        </g>
+    };
+
+    let axes = html! {
+        <g class="fill-text-tertiary text-xs font-mono tabular-nums">
+            {y_axis_labels(&price_ticks, &price, plot_right, |tick| format!("{:.*}", axis_decimals, tick))}
+            { for x_ticks.iter().map(|(idx, label)| html! {
+                <text x={center(*idx).to_string()} y={(plot_bottom + 16.0).to_string()} text-anchor="middle">
+                    {label.clone()}
+                </text>
+            }) }
+            if props.show_volume {
+                <text x={(plot_right + 8.0).to_string()} y={(plot_bottom - volume_height).to_string()} dominant-baseline="hanging">
+                    {NumberFormat::compact(1).format(max_volume)}
+                </text>
+            }
+        </g>
+    };
+
+    let overlay_lines = overlays.iter().map(|(_, color, points)| {
+        let path = points
+            .iter()
+            .enumerate()
+            .map(|(n, (idx, value))| format!("{}{:.2},{:.2}", if n == 0 { "M" } else { "L" }, center(*idx), price.map(*value)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        html! {
+            <path
+                d={path}
+                fill="none"
+                stroke="currentColor"
+                stroke-width="1.5"
+                stroke-linejoin="round"
+                class={classes!(color.text_class(), "pointer-events-none")}
+            />
+        }
+    });
+
+    let candle_marks = candles.iter().enumerate().map(|(idx, candle)| {
+        let x = center(idx);
+        let body_top = price.map(candle.open.max(candle.close));
+        let body_height = (price.map(candle.open.min(candle.close)) - body_top).max(1.0);
+        html! {
+            <g class={candle.fill_class()}>
+                <line
+                    x1={x.to_string()}
+                    y1={price.map(candle.high).to_string()}
+                    x2={x.to_string()}
+                    y2={price.map(candle.low).to_string()}
+                    stroke-width="1"
+                />
+                <rect
+                    x={(x - body_width / 2.0).to_string()}
+                    y={body_top.to_string()}
+                    width={body_width.to_string()}
+                    height={body_height.to_string()}
+                    stroke="none"
+                />
+                if props.show_volume {
+                    <rect
+                        x={(x - body_width / 2.0).to_string()}
+                        y={volume.map(candle.volume).to_string()}
+                        width={body_width.to_string()}
+                        height={(plot_bottom - volume.map(candle.volume)).max(0.0).to_string()}
+                        fill-opacity="0.4"
+                        stroke="none"
+                    />
+                }
+            </g>
+        }
+    });
+
+    // Crosshair: vela más cercana al mouse y precio a la altura del mouse
+    let hovered = pointer
+        .filter(|(x, y)| *x <= plot_right && *y <= plot_bottom)
+        .map(|(x, y)| (((x / slot) as usize).min(candles.len() - 1), y));
+
+    let crosshair = hovered.map(|(idx, y)| {
+        let x = center(idx);
+        let in_price_panel = y >= PLOT_TOP && y <= price_bottom;
+
+        html! {
+            <>
+                <g class="stroke-text-tertiary pointer-events-none" stroke-width="1" stroke-dasharray="3 3">
+                    <line x1={x.to_string()} y1={PLOT_TOP.to_string()} x2={x.to_string()} y2={plot_bottom.to_string()} />
+                    if in_price_panel {
+                        <line x1="0" y1={y.to_string()} x2={plot_right.to_string()} y2={y.to_string()} />
+                    }
+                </g>
+                if in_price_panel {
+                    {axis_marker(y, plot_right, format!("{:.*}", props.decimals, price.invert(y)))}
+                }
+            </>
+        }
+    });
+
+    let tooltip = hovered.map(|(idx, _)| {
+        let candle = &candles[idx];
+        let x = center(idx);
+        let change = candle.close - candle.open;
+        let change_pct = if candle.open != 0.0 { change / candle.open * 100.0 } else { 0.0 };
+        let change_type = ChangeType::from_delta(change);
+
+        let row = |label: &'static str, value: String| html! {
+            <>
+                <span class="text-text-tertiary">{label}</span>
+                <span class="text-right text-text-primary">{value}</span>
+            </>
+        };
+
+        chart_tooltip(x, plot_right, html! {
+            <>
+                <div class="text-text-tertiary mb-1">{format_timestamp(candle.time)}</div>
+                <div class="grid grid-cols-2 gap-x-3 gap-y-0.5 font-mono tabular-nums">
+                    {row("O", format!("{:.*}", props.decimals, candle.open))}
+                    {row("H", format!("{:.*}", props.decimals, candle.high))}
+                    {row("L", format!("{:.*}", props.decimals, candle.low))}
+                    {row("C", format!("{:.*}", props.decimals, candle.close))}
+                    {row("V", NumberFormat::compact(1).format(candle.volume))}
+                </div>
+                if !overlays.is_empty() {
+                    <div class="grid grid-cols-[auto_auto] gap-x-3 gap-y-0.5 mt-1 pt-1 border-t border-border-subtle">
+                        { for overlays.iter().filter_map(|(series, color, points)| {
+                            points.iter().find(|(point_idx, _)| *point_idx == idx).map(|(_, value)| html! {
+                                <>
+                                    <span class="flex items-center gap-1.5 text-text-secondary">
+                                        <span class={classes!("w-2", "h-2", "rounded-full", "bg-current", color.text_class())} />
+                                        {&series.name}
+                                    </span>
+                                    <span class="text-right font-mono tabular-nums text-text-primary">
+                                        {format!("{:.*}", props.decimals, value)}
+                                    </span>
+                                </>
+                            })
+                        }) }
+                    </div>
+                }
+                <div class={classes!("mt-1", "font-mono", "tabular-nums", change_type.text_class())}>
+                    {format!("{:+.*} ({:+.2}%)", props.decimals, change, change_pct)}
+                </div>
+            </>
+        })
+    });
+
+    let brush = match (props.brush, bounds, range) {
+        (true, Some(bounds), Some(range)) => html! {
+            <ChartBrush
+                points={Rc::new(props.candles.iter().map(|c| (c.time, c.close)).collect::<Vec<_>>())}
+                bounds={bounds}
+                range={range}
+                min_span={min_span}
+                width={plot_right}
+                onchange={range_state.set.clone()}
+            />
+        },
+        _ => html! {},
+    };
+
+    html! {
+        <div ref={container_ref} class="relative w-full select-none">
+            <svg
+                ref={svg_ref}
+                width={width.to_string()}
+                height={height.to_string()}
+                role="img"
+                aria-label={props.label.clone().unwrap_or_else(|| "Candlestick chart".to_string())}
+                class={classes!("block", props.zoomable.then_some(if zoom.panning { "cursor-grabbing" } else { "cursor-crosshair" }))}
+                onmousemove={onmousemove}
+                onmouseleave={onmouseleave}
+                onmousedown={zoom.onmousedown}
+                ondblclick={zoom.ondblclick}
+            >
+                {grid}
+                { for candle_marks }
+                { for overlay_lines }
+                {axes}
+                {crosshair}
+            </svg>
+            {tooltip}
+            {brush}
+        </div>
+    }
+}
@@ -0,0 +1,295 @@
+use yew::prelude::*;
+
+use super::scale::{nice_ticks, tick_decimals, LinearScale};
+use super::{
+    axis_marker, chart_tooltip, horizontal_grid, use_chart_pointer, use_element_width, y_axis_labels,
+    AXIS_HEIGHT, AXIS_WIDTH, PLOT_TOP,
+};
+use crate::order_book::{best_price, cumulative_levels, OrderSide};
+
+/// Tamaño acumulado de un lado del libro a un precio dado: todo lo que se
+/// llena yendo desde el mejor precio hasta `price` (0 dentro del spread)
+fn depth_at(levels: &[(f64, f64, f64)], price: f64, side: OrderSide) -> f64 {
+    levels
+        .iter()
+        .take_while(|(level, _, _)| match side {
+            OrderSide::Bid => *level >= price,
+            OrderSide::Ask => *level <= price,
+        })
+        .last()
+        .map_or(0.0, |(_, _, total)| *total)
+}
+
+/// DepthChart - Gráfico de profundidad de mercado
+///
+/// Tamaño acumulado de bids (a la izquierda) y asks (a la derecha) como
+/// áreas escalonadas, centrado en el mid price. Recibe la misma data que
+/// `OrderBook` (niveles `(precio, tamaño)` y `grouping`), así las dos
+/// vistas muestran lo mismo. El hover indica el precio y el tamaño
+/// acumulado del lado bajo el mouse.
+///
+/// Respeta el visual language:
+/// - Bids en bullish, asks en bearish: stroke 1.5px y área al 10%
+/// - Grid en border-subtle, labels xs en text-tertiary
+/// - Mid price con línea punteada en border-emphasis
+/// - Crosshair punteado, tooltip en bg-elevated con border sutil
+///
+/// # Props
+/// - `bids`: Vec<(f64, f64)> - Niveles de compra (precio, tamaño), en cualquier orden
+/// - `asks`: Vec<(f64, f64)> - Niveles de venta (precio, tamaño), en cualquier orden
+/// - `depth`: usize - Niveles por lado (default: 50)
+/// - `grouping`: f64 - Tick de agrupación (default: 0, sin agrupar)
+/// - `height`: f64 - Alto del gráfico en px (default: 300)
+/// - `decimals`: usize - Decimales de precios (default: 2)
+/// - `size_decimals`: usize - Decimales de tamaños (default: 4)
+/// - `label`: Option<String> - Descripción para lectores de pantalla
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use hubermann_ui::*;
+///
+/// html! {
+///     <div class="grid grid-cols-[280px_1fr] gap-4">
+///         <OrderBook bids={book.bids.clone()} asks={book.asks.clone()} grouping={*grouping} />
+///         <DepthChart bids={book.bids.clone()} asks={book.asks.clone()} grouping={*grouping} />
+///     </div>
+/// }
+/// ```
+#[derive(Properties, PartialEq)]
+pub struct DepthChartProps {
+    pub bids: Vec<(f64, f64)>,
+    pub asks: Vec<(f64, f64)>,
+    #[prop_or(50)]
+    pub depth: usize,
+    #[prop_or(0.0)]
+    pub grouping: f64,
+    #[prop_or(300.0)]
+    pub height: f64,
+    #[prop_or(2)]
+    pub decimals: usize,
+    #[prop_or(4)]
+    pub size_decimals: usize,
+    #[prop_or_default]
+    pub label: Option<String>,
+}
+
+#[function_component(DepthChart)]
+pub fn depth_chart(props: &DepthChartProps) -> Html {
+    let container_ref = use_node_ref();
+    let svg_ref = use_node_ref();
+    let width = use_element_width(container_ref.clone(), 640.0);
+    let (pointer, onmousemove, onmouseleave) = use_chart_pointer(svg_ref.clone());
+
+    let height = props.height;
+    let side_levels = |levels: &[(f64, f64)], side: OrderSide| {
+        let mut levels = cumulative_levels(levels, props.grouping, side);
+        levels.truncate(props.depth);
+        levels
+    };
+    let bids = side_levels(&props.bids, OrderSide::Bid);
+    let asks = side_levels(&props.asks, OrderSide::Ask);
+
+    // Mid con los niveles reales; con un solo lado, su mejor precio
+    let mid = match (best_price(&props.bids, OrderSide::Bid), best_price(&props.asks, OrderSide::Ask)) {
+        (Some(bid), Some(ask)) => (bid + ask) / 2.0,
+        (Some(best), None) | (None, Some(best)) => best,
+        (None, None) => {
+            return html! {
+                <div
+                    ref={container_ref}
+                    class="flex items-center justify-center w-full text-sm text-text-tertiary"
+                    style={format!("height: {}px", height)}
+                >
+                    {"No data"}
+                </div>
+            };
+        }
+    };
+
+    let plot_right = (width - AXIS_WIDTH).max(1.0);
+    let plot_bottom = height - AXIS_HEIGHT;
+
+    // Dominio simétrico alrededor del mid, hasta el nivel más lejano
+    let half = bids
+        .last()
+        .map(|(price, _, _)| mid - price)
+        .into_iter()
+        .chain(asks.last().map(|(price, _, _)| price - mid))
+        .fold(0.0, f64::max);
+    let half = if half > 0.0 { half } else { mid.abs() * 0.01 + 1.0 };
+    let (low, high) = (mid - half, mid + half);
+    let max_total = bids
+        .last()
+        .into_iter()
+        .chain(asks.last())
+        .map(|(_, _, total)| *total)
+        .fold(0.0, f64::max);
+    let top = if max_total > 0.0 { max_total * 1.1 } else { 1.0 };
+
+    let x = LinearScale::new((low, high), (0.0, plot_right));
+    let y = LinearScale::new((0.0, top), (plot_bottom, PLOT_TOP));
+
+    let y_ticks = nice_ticks(0.0, top, ((plot_bottom - PLOT_TOP) / 48.0).max(2.0) as usize);
+    let size_axis_decimals = match y_ticks.as_slice() {
+        [a, b, ..] => tick_decimals(b - a),
+        _ => props.size_decimals,
+    };
+    let x_ticks = nice_ticks(low, high, (plot_right / 96.0).max(2.0) as usize);
+    let price_axis_decimals = match x_ticks.as_slice() {
+        [a, b, ..] => tick_decimals(b - a),
+        _ => props.decimals,
+    };
+
+    let grid = html! {
+        <g class="stroke-border-subtle" stroke-width="1">
+            {horizontal_grid(&y_ticks, &y, plot_right)}
+            { for x_ticks.iter().map(|tick| {
+                let x = x.map(*tick).round() + 0.5;
+                html! { <line x1={x.to_string()} y1={PLOT_TOP.to_string()} x2={x.to_string()} y2={plot_bottom.to_string()} /> }
+            }) }
+        </g>
+    };
+
+    let axes = html! {
+        <g class="fill-text-tertiary text-xs font-mono tabular-nums">
+            {y_axis_labels(&y_ticks, &y, plot_right, |tick| format!("{:.*}", size_axis_decimals, tick))}
+            { for x_ticks.iter().map(|tick| html! {
+                <text x={x.map(*tick).to_string()} y={(plot_bottom + 16.0).to_string()} text-anchor="middle">
+                    {format!("{:.*}", price_axis_decimals, tick)}
+                </text>
+            }) }
+        </g>
+    };
+
+    // Escalones desde el mejor precio hacia afuera: entre un nivel y el
+    // siguiente el acumulado se mantiene; después del último sigue hasta el
+    // borde del gráfico
+    let area = |levels: &[(f64, f64, f64)], side: OrderSide| {
+        let Some((best, _, _)) = levels.first() else {
+            return html! {};
+        };
+        let edge = match side {
+            OrderSide::Bid => 0.0,
+            OrderSide::Ask => plot_right,
+        };
+
+        let mut steps = vec![(x.map(*best), plot_bottom)];
+        for (idx, (price, _, total)) in levels.iter().enumerate() {
+            let next = levels.get(idx + 1).map_or(edge, |(next, _, _)| x.map(*next));
+            steps.push((x.map(*price), y.map(*total)));
+            steps.push((next, y.map(*total)));
+        }
+        let line = steps
+            .iter()
+            .enumerate()
+            .map(|(idx, (px, py))| format!("{}{:.2},{:.2}", if idx == 0 { "M" } else { "L" }, px, py))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let fill = format!("{} L{:.2},{:.2} Z", line, edge, plot_bottom);
+
+        html! {
+            <g class={side.text_class()}>
+                <path d={fill} fill="currentColor" fill-opacity="0.1" stroke="none" />
+                <path d={line} fill="none" stroke="currentColor" stroke-width="1.5" stroke-linejoin="round" />
+            </g>
+        }
+    };
+
+    let mid_x = x.map(mid);
+    let mid_line = html! {
+        <g class="pointer-events-none">
+            <line
+                x1={mid_x.to_string()}
+                y1={PLOT_TOP.to_string()}
+                x2={mid_x.to_string()}
+                y2={plot_bottom.to_string()}
+                class="stroke-border-emphasis"
+                stroke-width="1"
+                stroke-dasharray="2 2"
+            />
+            <text
+                x={mid_x.to_string()}
+                y={(PLOT_TOP + 12.0).to_string()}
+                text-anchor="middle"
+                class="fill-text-secondary text-xs font-mono tabular-nums"
+            >
+                {format!("{:.*}", props.decimals + 1, mid)}
+            </text>
+        </g>
+    };
+
+    // Hover: el lado sale de qué lado del mid está el mouse
+    let hovered = pointer
+        .filter(|(px, py)| *px <= plot_right && *py <= plot_bottom)
+        .map(|(px, _)| {
+            let price = x.invert(px);
+            let (side, levels) = if price < mid {
+                (OrderSide::Bid, &bids)
+            } else {
+                (OrderSide::Ask, &asks)
+            };
+            (px, price, side, depth_at(levels, price, side))
+        });
+
+    let crosshair = hovered.map(|(px, _, side, total)| {
+        let cy = y.map(total);
+        html! {
+            <>
+                <g class="stroke-text-tertiary pointer-events-none" stroke-width="1" stroke-dasharray="3 3">
+                    <line x1={px.to_string()} y1={PLOT_TOP.to_string()} x2={px.to_string()} y2={plot_bottom.to_string()} />
+                    <line x1="0" y1={cy.to_string()} x2={plot_right.to_string()} y2={cy.to_string()} />
+                </g>
+                <circle
+                    cx={px.to_string()}
+                    cy={cy.to_string()}
+                    r="3"
+                    class={classes!(side.text_class(), "fill-current", "stroke-bg-secondary", "pointer-events-none")}
+                    stroke-width="1.5"
+                />
+                {axis_marker(cy, plot_right, format!("{:.*}", size_axis_decimals, total))}
+            </>
+        }
+    });
+
+    let tooltip = hovered.map(|(px, price, side, total)| {
+        let side_label = match side {
+            OrderSide::Bid => "Total bids",
+            OrderSide::Ask => "Total asks",
+        };
+        chart_tooltip(px, plot_right, html! {
+            <div class="grid grid-cols-[auto_auto] gap-x-3 gap-y-0.5">
+                <span class="text-text-tertiary">{"Price"}</span>
+                <span class="text-right font-mono tabular-nums text-text-primary">
+                    {format!("{:.*}", props.decimals, price)}
+                </span>
+                <span class="text-text-tertiary">{side_label}</span>
+                <span class={classes!("text-right", "font-mono", "tabular-nums", side.text_class())}>
+                    {format!("{:.*}", props.size_decimals, total)}
+                </span>
+            </div>
+        })
+    });
+
+    html! {
+        <div ref={container_ref} class="relative w-full select-none">
+            <svg
+                ref={svg_ref}
+                width={width.to_string()}
+                height={height.to_string()}
+                role="img"
+                aria-label={props.label.clone().unwrap_or_else(|| "Market depth chart".to_string())}
+                class="block cursor-crosshair"
+                onmousemove={onmousemove}
+                onmouseleave={onmouseleave}
+            >
+                {grid}
+                {area(&bids, OrderSide::Bid)}
+                {area(&asks, OrderSide::Ask)}
+                {mid_line}
+                {axes}
+                {crosshair}
+            </svg>
+            {tooltip}
+        </div>
+    }
+}
@@ -0,0 +1,510 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::range::{min_span, use_chart_range, use_chart_zoom, ChartBrush, ChartRange};
+use super::scale::{format_timestamp, nice_ticks, tick_decimals, time_ticks, LinearScale};
+use super::{
+    axis_marker, chart_tooltip, horizontal_grid, use_chart_pointer, use_element_width, y_axis_labels,
+    AXIS_HEIGHT, AXIS_WIDTH, PLOT_TOP,
+};
+
+/// Color de una serie del `LineChart`
+///
+/// Sin color explícito cada serie toma el siguiente de la paleta, en este
+/// orden.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SeriesColor {
+    Accent,
+    Neutral,
+    Warning,
+    Muted,
+    Bullish,
+    Bearish,
+}
+
+impl SeriesColor {
+    const PALETTE: [SeriesColor; 6] = [
+        SeriesColor::Accent,
+        SeriesColor::Neutral,
+        SeriesColor::Warning,
+        SeriesColor::Muted,
+        SeriesColor::Bullish,
+        SeriesColor::Bearish,
+    ];
+
+    /// Color explícito de la serie o el de la paleta para su posición
+    pub(crate) fn of(series: &LineSeries, idx: usize) -> Self {
+        series.color.unwrap_or(Self::PALETTE[idx % Self::PALETTE.len()])
+    }
+
+    pub(crate) fn text_class(&self) -> &'static str {
+        match self {
+            SeriesColor::Accent => "text-accent",
+            SeriesColor::Neutral => "text-neutral",
+            SeriesColor::Warning => "text-warning",
+            SeriesColor::Muted => "text-text-secondary",
+            SeriesColor::Bullish => "text-bullish",
+            SeriesColor::Bearish => "text-bearish",
+        }
+    }
+}
+
+/// Serie con nombre de un `LineChart`: puntos (timestamp unix en segundos,
+/// valor) en orden temporal
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// LineSeries::new("Portfolio", equity_points).with_area();
+/// LineSeries::new("S&P 500", benchmark_points).with_color(SeriesColor::Muted);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct LineSeries {
+    pub name: String,
+    pub points: Vec<(i64, f64)>,
+    pub color: Option<SeriesColor>,
+    /// Relleno debajo de la línea
+    pub area: bool,
+}
+
+impl LineSeries {
+    pub fn new(name: impl Into<String>, points: Vec<(i64, f64)>) -> Self {
+        Self {
+            name: name.into(),
+            points,
+            color: None,
+            area: false,
+        }
+    }
+
+    pub fn with_color(mut self, color: SeriesColor) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_area(mut self) -> Self {
+        self.area = true;
+        self
+    }
+}
+
+/// Rebasa una serie a variación porcentual desde su primer punto
+///
+/// Sirve para comparar series de escalas distintas (equity vs benchmark).
+/// Si el primer valor es 0 no hay base y la serie queda vacía.
+///
+/// ```
+/// use hubermann_ui::rebase_percent;
+///
+/// let equity = vec![(1, 200.0), (2, 210.0), (3, 190.0)];
+/// assert_eq!(rebase_percent(&equity), vec![(1, 0.0), (2, 5.0), (3, -5.0)]);
+/// ```
+pub fn rebase_percent(points: &[(i64, f64)]) -> Vec<(i64, f64)> {
+    match points.first() {
+        Some((_, base)) if *base != 0.0 => points
+            .iter()
+            .map(|(time, value)| (*time, (value - base) / base * 100.0))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Último punto en o antes de `time` (la serie mantiene su valor entre
+/// puntos); None si la serie todavía no empezó
+fn point_at(points: &[(i64, f64)], time: i64) -> Option<(i64, f64)> {
+    match points.partition_point(|(t, _)| *t <= time) {
+        0 => None,
+        idx => Some(points[idx - 1]),
+    }
+}
+
+/// LineChart - Gráfico de líneas/áreas con varias series
+///
+/// Para equity curves y comparaciones contra benchmarks. Los ejes se
+/// autoescalan a las series visibles; con `normalize` cada serie se rebasa
+/// a 0% en su primer punto visible (ver `rebase_percent`). La leyenda permite
+/// ocultar/mostrar series y el crosshair es compartido: el tooltip lista el
+/// valor de cada serie en el tiempo bajo el mouse.
+///
+/// Zoom, pan y brush funcionan igual que en `CandlestickChart` (props
+/// `zoomable`, `brush`, `range` y `onrangechange`); el brush muestra la
+/// primera serie.
+///
+/// Respeta el visual language:
+/// - Stroke 1.5px, áreas al 10% del color de la serie
+/// - Grid en border-subtle, labels xs en text-tertiary
+/// - Crosshair punteado, tooltip en bg-elevated con border sutil
+/// - Leyenda text-xs, series ocultas atenuadas y tachadas
+///
+/// # Props
+/// - `series`: Vec<LineSeries> - Series con nombre (puntos en orden temporal)
+/// - `height`: f64 - Alto del gráfico en px (default: 300)
+/// - `normalize`: bool - Modo porcentual rebasado al primer punto (default: false)
+/// - `decimals`: usize - Decimales de valores en tooltip y crosshair (default: 2)
+/// - `show_legend`: bool - Leyenda con toggles (default: true)
+/// - `label`: Option<String> - Descripción para lectores de pantalla
+/// - `zoomable`: bool - Zoom con la rueda, pan y doble click para resetear (default: false)
+/// - `brush`: bool - Vista general con la ventana visible debajo (default: false)
+/// - `range`: Option<ChartRange> - Rango visible controlado
+/// - `onrangechange`: Option<Callback<ChartRange>> - Cambios de rango (zoom, pan, brush)
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use hubermann_ui::*;
+///
+/// html! {
+///     <LineChart
+///         series={vec![
+///             LineSeries::new("Portfolio", equity).with_area(),
+///             LineSeries::new("S&P 500", benchmark).with_color(SeriesColor::Muted),
+///         ]}
+///         normalize={true}
+///     />
+/// }
+/// ```
+#[derive(Properties, PartialEq)]
+pub struct LineChartProps {
+    pub series: Vec<LineSeries>,
+    #[prop_or(300.0)]
+    pub height: f64,
+    #[prop_or(false)]
+    pub normalize: bool,
+    #[prop_or(2)]
+    pub decimals: usize,
+    #[prop_or(true)]
+    pub show_legend: bool,
+    #[prop_or_default]
+    pub label: Option<String>,
+    #[prop_or(false)]
+    pub zoomable: bool,
+    #[prop_or(false)]
+    pub brush: bool,
+    #[prop_or_default]
+    pub range: Option<ChartRange>,
+    #[prop_or_default]
+    pub onrangechange: Option<Callback<ChartRange>>,
+}
+
+#[function_component(LineChart)]
+pub fn line_chart(props: &LineChartProps) -> Html {
+    let container_ref = use_node_ref();
+    let svg_ref = use_node_ref();
+    let width = use_element_width(container_ref.clone(), 640.0);
+    let (pointer, onmousemove, onmouseleave) = use_chart_pointer(svg_ref.clone());
+    let hidden = use_state(HashSet::<String>::new);
+
+    let plot_right = (width - AXIS_WIDTH).max(1.0);
+    let bounds = ChartRange::of(props.series.iter().flat_map(|s| s.points.iter().map(|(time, _)| *time)));
+    let longest = props.series.iter().map(|s| s.points.len()).max().unwrap_or(0);
+    let min_span = bounds.map_or(0, |bounds| min_span(bounds, longest));
+    let range_state = use_chart_range(bounds, min_span, props.range, props.onrangechange.clone());
+    let zoom = use_chart_zoom(svg_ref.clone(), props.zoomable, plot_right, range_state.clone());
+    let range = range_state.current;
+
+    let height = props.height;
+    let format_value = |value: f64| match props.normalize {
+        true => format!("{:+.*}%", props.decimals, value),
+        false => format!("{:.*}", props.decimals, value),
+    };
+
+    // Series con color asignado, recortadas al rango visible y, en modo
+    // porcentual, rebasadas al primer punto visible
+    let series: Vec<(LineSeries, SeriesColor)> = props
+        .series
+        .iter()
+        .enumerate()
+        .map(|(idx, series)| {
+            let color = SeriesColor::of(series, idx);
+            let in_range: Vec<(i64, f64)> = series
+                .points
+                .iter()
+                .copied()
+                .filter(|(time, _)| range.is_none_or(|range| range.contains(*time)))
+                .collect();
+            let points = match props.normalize {
+                true => rebase_percent(&in_range),
+                false => in_range,
+            };
+            (LineSeries { points, ..series.clone() }, color)
+        })
+        .collect();
+    let visible: Vec<&(LineSeries, SeriesColor)> = series
+        .iter()
+        .filter(|(series, _)| !hidden.contains(&series.name) && !series.points.is_empty())
+        .collect();
+
+    let legend = props.show_legend.then(|| {
+        html! {
+            <div class="flex flex-wrap items-center gap-x-4 gap-y-1 mb-2 text-xs">
+                { for series.iter().map(|(series, color)| {
+                    let is_hidden = hidden.contains(&series.name);
+                    let ontoggle = {
+                        let hidden = hidden.clone();
+                        let name = series.name.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            let mut next = (*hidden).clone();
+                            if !next.remove(&name) {
+                                next.insert(name.clone());
+                            }
+                            hidden.set(next);
+                        })
+                    };
+
+                    html! {
+                        <button
+                            type="button"
+                            aria-pressed={(!is_hidden).to_string()}
+                            onclick={ontoggle}
+                            class={classes!(
+                                "flex", "items-center", "gap-1.5", "text-text-secondary",
+                                "hover:text-text-primary", "transition-colors",
+                                is_hidden.then_some("opacity-50 line-through"),
+                            )}
+                        >
+                            <span class={classes!("w-3", "h-0.5", "rounded-full", "bg-current", color.text_class())} />
+                            {&series.name}
+                        </button>
+                    }
+                }) }
+            </div>
+        }
+    });
+
+    let times: Vec<i64> = {
+        let mut times: Vec<i64> = visible
+            .iter()
+            .flat_map(|(series, _)| series.points.iter().map(|(time, _)| *time))
+            .collect();
+        times.sort_unstable();
+        times.dedup();
+        times
+    };
+
+    let (Some(first_time), Some(last_time)) = (times.first().copied(), times.last().copied()) else {
+        return html! {
+            <div ref={container_ref} class="w-full">
+                {legend}
+                <div
+                    class="flex items-center justify-center text-sm text-text-tertiary"
+                    style={format!("height: {}px", height)}
+                >
+                    {"No data"}
+                </div>
+            </div>
+        };
+    };
+
+    let plot_bottom = height - AXIS_HEIGHT;
+
+    // En modo porcentual el 0% siempre queda a la vista
+    let values = visible
+        .iter()
+        .flat_map(|(series, _)| series.points.iter().map(|(_, value)| *value))
+        .chain(props.normalize.then_some(0.0));
+    let low = values.clone().fold(f64::INFINITY, f64::min);
+    let high = values.fold(f64::NEG_INFINITY, f64::max);
+    let padding = match high - low {
+        range if range > 0.0 => range * 0.05,
+        _ => high.abs() * 0.01 + 1.0,
+    };
+    let (x_start, x_end) = range.map_or((first_time, last_time), |range| (range.start, range.end));
+    let x = LinearScale::new((x_start as f64, x_end as f64), (0.0, plot_right));
+    let y = LinearScale::new((low - padding, high + padding), (plot_bottom, PLOT_TOP));
+
+    let y_ticks = nice_ticks(low - padding, high + padding, ((plot_bottom - PLOT_TOP) / 48.0).max(2.0) as usize);
+    let axis_decimals = match y_ticks.as_slice() {
+        [a, b, ..] => tick_decimals(b - a),
+        _ => props.decimals,
+    };
+    let x_ticks = time_ticks(&times, (plot_right / 96.0).max(2.0) as usize);
+    let time_x = |time: i64| x.map(time as f64);
+
+    let grid = html! {
+        <g class="stroke-border-subtle" stroke-width="1">
+            {horizontal_grid(&y_ticks, &y, plot_right)}
+            { for x_ticks.iter().map(|(idx, _)| {
+                let x = time_x(times[*idx]).round() + 0.5;
+                html! { <line x1={x.to_string()} y1={PLOT_TOP.to_string()} x2={x.to_string()} y2={plot_bottom.to_string()} /> }
+            }) }
+            if props.normalize {
+                <line
+                    x1="0"
+                    y1={y.map(0.0).to_string()}
+                    x2={plot_right.to_string()}
+                    y2={y.map(0.0).to_string()}
+                    class="stroke-border-emphasis"
+                    stroke-dasharray="2 2"
+                />
+            }
+        </g>
+    };
+
+    let axes = html! {
+        <g class="fill-text-tertiary text-xs font-mono tabular-nums">
+            {y_axis_labels(&y_ticks, &y, plot_right, |tick| match props.normalize {
+                true => format!("{:.*}%", axis_decimals, tick),
+                false => format!("{:.*}", axis_decimals, tick),
+            })}
+            { for x_ticks.iter().map(|(idx, label)| html! {
+                <text x={time_x(times[*idx]).to_string()} y={(plot_bottom + 16.0).to_string()} text-anchor="middle">
+                    {label.clone()}
+                </text>
+            }) }
+        </g>
+    };
+
+    let lines = visible.iter().map(|(series, color)| {
+        let path = series
+            .points
+            .iter()
+            .enumerate()
+            .map(|(idx, (time, value))| {
+                format!("{}{:.2},{:.2}", if idx == 0 { "M" } else { "L" }, time_x(*time), y.map(*value))
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        // En modo normalize el área se cierra contra la línea de 0% (así
+        // una serie que cae queda rellena hacia arriba); si no, contra el
+        // fondo del plot
+        let baseline = match props.normalize {
+            true => y.map(0.0).clamp(PLOT_TOP, plot_bottom),
+            false => plot_bottom,
+        };
+        let area = series.area.then(|| {
+            let (first, last) = (series.points[0].0, series.points[series.points.len() - 1].0);
+            format!(
+                "{} L{:.2},{:.2} L{:.2},{:.2} Z",
+                path,
+                time_x(last),
+                baseline,
+                time_x(first),
+                baseline
+            )
+        });
+
+        html! {
+            <g class={color.text_class()}>
+                if let Some(area) = area {
+                    <path d={area} fill="currentColor" fill-opacity="0.1" stroke="none" />
+                }
+                <path
+                    d={path}
+                    fill="none"
+                    stroke="currentColor"
+                    stroke-width="1.5"
+                    stroke-linejoin="round"
+                    stroke-linecap="round"
+                />
+            </g>
+        }
+    });
+
+    // Crosshair compartido: se engancha al timestamp más cercano al mouse
+    let hovered = pointer
+        .filter(|(px, py)| *px <= plot_right && *py <= plot_bottom)
+        .map(|(px, py)| {
+            let time = x.invert(px);
+            let nearest = times
+                .iter()
+                .copied()
+                .min_by(|a, b| (*a as f64 - time).abs().total_cmp(&(*b as f64 - time).abs()))
+                .unwrap_or(first_time);
+            (nearest, py)
+        });
+
+    let crosshair = hovered.map(|(time, py)| {
+        let cx = time_x(time);
+        let in_plot = py >= PLOT_TOP;
+
+        html! {
+            <>
+                <g class="stroke-text-tertiary pointer-events-none" stroke-width="1" stroke-dasharray="3 3">
+                    <line x1={cx.to_string()} y1={PLOT_TOP.to_string()} x2={cx.to_string()} y2={plot_bottom.to_string()} />
+                    if in_plot {
+                        <line x1="0" y1={py.to_string()} x2={plot_right.to_string()} y2={py.to_string()} />
+                    }
+                </g>
+                { for visible.iter().filter_map(|(series, color)| {
+                    point_at(&series.points, time).map(|(point_time, value)| html! {
+                        <circle
+                            cx={time_x(point_time).to_string()}
+                            cy={y.map(value).to_string()}
+                            r="3"
+                            class={classes!(color.text_class(), "fill-current", "stroke-bg-secondary", "pointer-events-none")}
+                            stroke-width="1.5"
+                        />
+                    })
+                }) }
+                if in_plot {
+                    {axis_marker(py, plot_right, format_value(y.invert(py)))}
+                }
+            </>
+        }
+    });
+
+    let tooltip = hovered.map(|(time, _)| {
+        chart_tooltip(time_x(time), plot_right, html! {
+            <>
+                <div class="text-text-tertiary mb-1">{format_timestamp(time)}</div>
+                <div class="grid grid-cols-[auto_auto] gap-x-3 gap-y-0.5">
+                    { for visible.iter().filter_map(|(series, color)| {
+                        point_at(&series.points, time).map(|(_, value)| html! {
+                            <>
+                                <span class="flex items-center gap-1.5 text-text-secondary">
+                                    <span class={classes!("w-2", "h-2", "rounded-full", "bg-current", color.text_class())} />
+                                    {&series.name}
+                                </span>
+                                <span class="text-right font-mono tabular-nums text-text-primary">
+                                    {format_value(value)}
+                                </span>
+                            </>
+                        })
+                    }) }
+                </div>
+            </>
+        })
+    });
+
+    let brush = match (props.brush, bounds, range, props.series.first()) {
+        (true, Some(bounds), Some(range), Some(first)) => html! {
+            <ChartBrush
+                points={Rc::new(first.points.clone())}
+                bounds={bounds}
+                range={range}
+                min_span={min_span}
+                width={plot_right}
+                onchange={range_state.set.clone()}
+            />
+        },
+        _ => html! {},
+    };
+
+    html! {
+        <div ref={container_ref} class="w-full select-none">
+            {legend}
+            <div class="relative">
+                <svg
+                    ref={svg_ref}
+                    width={width.to_string()}
+                    height={height.to_string()}
+                    role="img"
+                    aria-label={props.label.clone().unwrap_or_else(|| "Line chart".to_string())}
+                    class={classes!("block", props.zoomable.then_some(if zoom.panning { "cursor-grabbing" } else { "cursor-crosshair" }))}
+                    onmousemove={onmousemove}
+                    onmouseleave={onmouseleave}
+                    onmousedown={zoom.onmousedown}
+                    ondblclick={zoom.ondblclick}
+                >
+                    {grid}
+                    { for lines }
+                    {axes}
+                    {crosshair}
+                </svg>
+                {tooltip}
+            </div>
+            {brush}
+        </div>
+    }
+}
@@ -0,0 +1,155 @@
+use gloo::events::EventListener;
+use gloo::utils::window;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+mod candlestick;
+mod depth;
+mod line;
+mod range;
+mod scale;
+
+pub use candlestick::*;
+pub use depth::*;
+pub use line::*;
+pub use range::*;
+pub use scale::*;
+
+/// Ancho del eje de precios (derecha)
+pub(crate) const AXIS_WIDTH: f64 = 64.0;
+/// Alto del eje de tiempo (abajo)
+pub(crate) const AXIS_HEIGHT: f64 = 24.0;
+/// Margen superior del área de trazado
+pub(crate) const PLOT_TOP: f64 = 8.0;
+
+/// Ancho en px del elemento, actualizado al redimensionar la ventana
+///
+/// Los charts se dibujan en px reales (no con `viewBox` estirado) para
+/// que el texto y los strokes no se deformen.
+#[hook]
+pub(crate) fn use_element_width(node: NodeRef, fallback: f64) -> f64 {
+    let width = use_state_eq(|| fallback);
+
+    {
+        let width = width.clone();
+        use_effect_with(node, move |node| {
+            let measure = {
+                let node = node.clone();
+                move || {
+                    if let Some(element) = node.cast::<HtmlElement>() {
+                        if element.client_width() > 0 {
+                            width.set(element.client_width() as f64);
+                        }
+                    }
+                }
+            };
+
+            measure();
+            let listener = EventListener::new(&window(), "resize", move |_| measure());
+            move || drop(listener)
+        });
+    }
+
+    *width
+}
+
+/// Posición del mouse relativa al SVG, para crosshairs
+///
+/// Devuelve la posición actual (None fuera del chart) y los handlers de
+/// mousemove/mouseleave para el `<svg>` de `svg_ref`.
+#[hook]
+pub(crate) fn use_chart_pointer(
+    svg_ref: NodeRef,
+) -> (Option<(f64, f64)>, Callback<MouseEvent>, Callback<MouseEvent>) {
+    let pointer = use_state_eq(|| None::<(f64, f64)>);
+
+    let onmousemove = {
+        let pointer = pointer.clone();
+        Callback::from(move |e: MouseEvent| {
+            if let Some(svg) = svg_ref.cast::<Element>() {
+                let rect = svg.get_bounding_client_rect();
+                pointer.set(Some((
+                    e.client_x() as f64 - rect.left(),
+                    e.client_y() as f64 - rect.top(),
+                )));
+            }
+        })
+    };
+
+    let onmouseleave = {
+        let pointer = pointer.clone();
+        Callback::from(move |_: MouseEvent| pointer.set(None))
+    };
+
+    (*pointer, onmousemove, onmouseleave)
+}
+
+/// Líneas horizontales del grid en cada tick del eje Y
+pub(crate) fn horizontal_grid(ticks: &[f64], scale: &LinearScale, plot_right: f64) -> Html {
+    html! {
+        <g class="stroke-border-subtle" stroke-width="1">
+            { for ticks.iter().map(|tick| {
+                // +0.5 para que la línea de 1px caiga justo en un pixel
+                let y = scale.map(*tick).round() + 0.5;
+                html! { <line x1="0" y1={y.to_string()} x2={plot_right.to_string()} y2={y.to_string()} /> }
+            }) }
+        </g>
+    }
+}
+
+/// Labels del eje Y (a la derecha del área de trazado)
+pub(crate) fn y_axis_labels(ticks: &[f64], scale: &LinearScale, plot_right: f64, label: impl Fn(f64) -> String) -> Html {
+    html! {
+        <g class="fill-text-tertiary text-xs font-mono tabular-nums">
+            { for ticks.iter().map(|tick| html! {
+                <text x={(plot_right + 8.0).to_string()} y={scale.map(*tick).to_string()} dominant-baseline="middle">
+                    {label(*tick)}
+                </text>
+            }) }
+        </g>
+    }
+}
+
+/// Valor del crosshair sobre el eje Y
+pub(crate) fn axis_marker(y: f64, plot_right: f64, label: String) -> Html {
+    html! {
+        <g class="pointer-events-none">
+            <rect
+                x={plot_right.to_string()}
+                y={(y - 9.0).to_string()}
+                width={AXIS_WIDTH.to_string()}
+                height="18"
+                rx="2"
+                class="fill-bg-elevated stroke-border-emphasis"
+                stroke-width="1"
+            />
+            <text
+                x={(plot_right + 8.0).to_string()}
+                y={y.to_string()}
+                dominant-baseline="middle"
+                class="fill-text-primary text-xs font-mono tabular-nums"
+            >
+                {label}
+            </text>
+        </g>
+    }
+}
+
+/// Tooltip flotante del crosshair, del lado opuesto al mouse para no tapar
+/// lo que se está mirando
+pub(crate) fn chart_tooltip(x: f64, plot_right: f64, content: Html) -> Html {
+    let position = if x < plot_right / 2.0 {
+        format!("left: {}px; top: {}px", x + 16.0, PLOT_TOP + 8.0)
+    } else {
+        format!("left: {}px; top: {}px; transform: translateX(-100%)", x - 16.0, PLOT_TOP + 8.0)
+    };
+
+    html! {
+        <div
+            class="absolute pointer-events-none bg-bg-elevated border border-border-default rounded-md shadow-md px-2 py-1.5 text-xs"
+            style={position}
+        >
+            {content}
+        </div>
+    }
+}
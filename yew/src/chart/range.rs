@@ -0,0 +1,453 @@
+use std::rc::Rc;
+
+use gloo::events::{EventListener, EventListenerOptions};
+use gloo::utils::document;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, WheelEvent};
+use yew::prelude::*;
+
+/// Cantidad mínima de puntos visibles al hacer zoom
+pub(crate) const MIN_VISIBLE_POINTS: i64 = 5;
+/// Factor de zoom por paso de la rueda del mouse
+const WHEEL_ZOOM: f64 = 1.2;
+
+/// Rango de tiempo visible de un chart (timestamps unix en segundos)
+///
+/// Es el estado que manejan el zoom, el pan y el brush. Se puede controlar
+/// desde afuera (prop `range` de los charts) para vincularlo, por ejemplo,
+/// a unas `Tabs` de timeframe.
+///
+/// ```
+/// use hubermann_ui::ChartRange;
+///
+/// let bounds = ChartRange::new(0, 1_000);
+/// let range = ChartRange::new(400, 600);
+///
+/// // Zoom in al doble alrededor del centro
+/// assert_eq!(range.zoom(0.5, 0.5, bounds, 10), ChartRange::new(450, 550));
+/// // Pan: no se sale de los datos
+/// assert_eq!(range.pan(500, bounds), ChartRange::new(800, 1_000));
+/// // Timeframe "última hora" para unas Tabs
+/// assert_eq!(ChartRange::last(3_600, 10_000), ChartRange::new(6_400, 10_000));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ChartRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl ChartRange {
+    pub fn new(start: i64, end: i64) -> Self {
+        Self {
+            start: start.min(end),
+            end: start.max(end),
+        }
+    }
+
+    /// Los últimos `duration` segundos hasta `end` (timeframes 1H/4H/1D)
+    pub fn last(duration: i64, end: i64) -> Self {
+        Self::new(end - duration, end)
+    }
+
+    /// Rango completo de una serie de timestamps
+    pub fn of(times: impl IntoIterator<Item = i64>) -> Option<Self> {
+        times.into_iter().fold(None, |range, time| match range {
+            None => Some(Self::new(time, time)),
+            Some(range) => Some(Self::new(range.start.min(time), range.end.max(time))),
+        })
+    }
+
+    pub fn span(&self) -> i64 {
+        self.end - self.start
+    }
+
+    pub fn contains(&self, time: i64) -> bool {
+        (self.start..=self.end).contains(&time)
+    }
+
+    /// Ajusta el rango para que entre en `bounds` con al menos `min_span`,
+    /// desplazándolo (sin achicarlo) si se sale por un costado
+    ///
+    /// ```
+    /// use hubermann_ui::ChartRange;
+    ///
+    /// let bounds = ChartRange::new(0, 1_000);
+    /// assert_eq!(ChartRange::new(-100, 100).clamp(bounds, 10), ChartRange::new(0, 200));
+    /// assert_eq!(ChartRange::new(500, 502).clamp(bounds, 10), ChartRange::new(496, 506));
+    /// assert_eq!(ChartRange::new(-50, 5_000).clamp(bounds, 10), bounds);
+    /// ```
+    pub fn clamp(&self, bounds: ChartRange, min_span: i64) -> Self {
+        let span = self.span().max(min_span).min(bounds.span());
+        let center = self.start + self.span() / 2;
+        let start = (center - span / 2).clamp(bounds.start, bounds.end - span);
+        Self::new(start, start + span)
+    }
+
+    /// Zoom por `factor` (<1 acerca, >1 aleja) manteniendo fijo el punto en
+    /// `anchor` (0 = borde izquierdo, 1 = derecho), donde está el mouse
+    pub fn zoom(&self, anchor: f64, factor: f64, bounds: ChartRange, min_span: i64) -> Self {
+        let anchor = anchor.clamp(0.0, 1.0);
+        let span = self.span() as f64;
+        let next_span = (span * factor).round().max(1.0);
+        let pivot = self.start as f64 + span * anchor;
+        let start = (pivot - next_span * anchor).round() as i64;
+        Self::new(start, start + next_span as i64).clamp(bounds, min_span)
+    }
+
+    /// Desplaza el rango `delta` segundos sin salirse de `bounds`
+    pub fn pan(&self, delta: i64, bounds: ChartRange) -> Self {
+        let span = self.span().min(bounds.span());
+        let start = (self.start + delta).clamp(bounds.start, bounds.end - span);
+        Self::new(start, start + span)
+    }
+}
+
+/// Span mínimo al hacer zoom: `MIN_VISIBLE_POINTS` intervalos promedio
+pub(crate) fn min_span(bounds: ChartRange, points: usize) -> i64 {
+    match points {
+        0 | 1 => bounds.span(),
+        n => (bounds.span() / (n as i64 - 1) * MIN_VISIBLE_POINTS).min(bounds.span()),
+    }
+}
+
+/// Rango visible de un chart y cómo cambiarlo
+#[derive(Clone, PartialEq)]
+pub(crate) struct ChartRangeState {
+    /// Rango visible; None sin datos
+    pub current: Option<ChartRange>,
+    /// Rango completo de los datos
+    pub bounds: Option<ChartRange>,
+    pub min_span: i64,
+    /// Actualiza el estado interno si no es controlado y emite `onrangechange`
+    pub set: Callback<ChartRange>,
+}
+
+/// Rango visible: el controlado (`range`) o el interno, ajustado a los
+/// datos; sin zoom es todo `bounds`
+#[hook]
+pub(crate) fn use_chart_range(
+    bounds: Option<ChartRange>,
+    min_span: i64,
+    range: Option<ChartRange>,
+    onrangechange: Option<Callback<ChartRange>>,
+) -> ChartRangeState {
+    let internal = use_state_eq(|| None::<ChartRange>);
+
+    let current = bounds.map(|bounds| {
+        range
+            .or(*internal)
+            .map(|range| range.clamp(bounds, min_span))
+            .unwrap_or(bounds)
+    });
+
+    let set = {
+        let internal = internal.clone();
+        let controlled = range.is_some();
+        Callback::from(move |next: ChartRange| {
+            if !controlled {
+                internal.set(Some(next));
+            }
+            if let Some(callback) = &onrangechange {
+                callback.emit(next);
+            }
+        })
+    };
+
+    ChartRangeState {
+        current,
+        bounds,
+        min_span,
+        set,
+    }
+}
+
+/// Arrastre horizontal: `start` recibe un payload y el `client_x` inicial;
+/// mientras dura, `onmove` recibe el payload y el desplazamiento en px
+///
+/// Escucha mousemove/mouseup en el document para seguir el arrastre
+/// aunque el mouse salga del chart. Devuelve también si hay un arrastre en
+/// curso (para el cursor).
+#[hook]
+pub(crate) fn use_drag<S>(onmove: Callback<(S, i32)>) -> (Callback<(S, i32)>, bool)
+where
+    S: Clone + PartialEq + 'static,
+{
+    let drag = use_state(|| None::<(S, i32)>);
+
+    {
+        let drag_handle = drag.clone();
+        use_effect_with((*drag).clone(), move |drag| {
+            let listeners = drag.clone().map(|(payload, start_x)| {
+                let document = document();
+
+                let on_move = EventListener::new(&document, "mousemove", move |event| {
+                    if let Some(event) = event.dyn_ref::<MouseEvent>() {
+                        onmove.emit((payload.clone(), event.client_x() - start_x));
+                    }
+                });
+                let on_up = EventListener::new(&document, "mouseup", move |_| drag_handle.set(None));
+
+                (on_move, on_up)
+            });
+
+            move || drop(listeners)
+        });
+    }
+
+    let start = {
+        let drag = drag.clone();
+        Callback::from(move |start: (S, i32)| drag.set(Some(start)))
+    };
+
+    (start, drag.is_some())
+}
+
+/// Handlers de zoom y pan de un chart
+#[derive(Clone, PartialEq)]
+pub(crate) struct ChartZoom {
+    pub onmousedown: Callback<MouseEvent>,
+    pub ondblclick: Callback<MouseEvent>,
+    pub panning: bool,
+}
+
+/// Rueda = zoom alrededor del mouse, arrastre = pan, doble click = reset
+///
+/// La rueda se escucha directo en el SVG (no pasiva) para poder frenar el
+/// scroll de la página mientras se hace zoom.
+#[hook]
+pub(crate) fn use_chart_zoom(svg_ref: NodeRef, enabled: bool, plot_width: f64, state: ChartRangeState) -> ChartZoom {
+    let ChartRangeState {
+        current: range,
+        bounds,
+        set: onchange,
+        ..
+    } = state.clone();
+
+    // El listener de la rueda vive más que un render: lee el último estado
+    let latest = use_mut_ref(|| (state.clone(), plot_width));
+    *latest.borrow_mut() = (state, plot_width);
+
+    {
+        let latest = latest.clone();
+        // El SVG recién existe cuando hay datos
+        use_effect_with((svg_ref, enabled, bounds.is_some()), move |(svg_ref, enabled, _)| {
+            let listener = svg_ref.cast::<Element>().filter(|_| *enabled).map(|svg| {
+                let target = svg.clone();
+                EventListener::new_with_options(
+                    &target,
+                    "wheel",
+                    EventListenerOptions::enable_prevent_default(),
+                    move |event| {
+                        let (state, plot_width) = &*latest.borrow();
+                        let (Some(event), Some(range), Some(bounds)) =
+                            (event.dyn_ref::<WheelEvent>(), state.current, state.bounds)
+                        else {
+                            return;
+                        };
+
+                        event.prevent_default();
+                        let x = event.client_x() as f64 - svg.get_bounding_client_rect().left();
+                        let factor = if event.delta_y() > 0.0 { WHEEL_ZOOM } else { 1.0 / WHEEL_ZOOM };
+                        let next = range.zoom(x / plot_width, factor, bounds, state.min_span);
+                        if next != range {
+                            state.set.emit(next);
+                        }
+                    },
+                )
+            });
+
+            move || drop(listener)
+        });
+    }
+
+    let (start_pan, panning) = use_drag({
+        let onchange = onchange.clone();
+        Callback::from(move |(initial, dx): (ChartRange, i32)| {
+            if let (Some(bounds), true) = (bounds, dx != 0) {
+                // Arrastrar a la derecha muestra datos anteriores
+                let delta = -(dx as f64 / plot_width * initial.span() as f64).round() as i64;
+                onchange.emit(initial.pan(delta, bounds));
+            }
+        })
+    });
+
+    let onmousedown = Callback::from(move |e: MouseEvent| {
+        if let (true, 0, Some(range)) = (enabled, e.button(), range) {
+            e.prevent_default();
+            start_pan.emit((range, e.client_x()));
+        }
+    });
+
+    let ondblclick = Callback::from(move |_: MouseEvent| {
+        if let (true, Some(bounds)) = (enabled, bounds) {
+            onchange.emit(bounds);
+        }
+    });
+
+    ChartZoom {
+        onmousedown,
+        ondblclick,
+        panning,
+    }
+}
+
+/// Parte del brush que se arrastra
+#[derive(Clone, Copy, PartialEq)]
+enum BrushHandle {
+    Window,
+    Start,
+    End,
+}
+
+/// Props de `ChartBrush`
+#[derive(Properties, PartialEq)]
+pub(crate) struct ChartBrushProps {
+    /// Serie completa para la vista general
+    pub points: Rc<Vec<(i64, f64)>>,
+    pub bounds: ChartRange,
+    pub range: ChartRange,
+    pub min_span: i64,
+    /// Ancho en px (el del área de trazado del chart)
+    pub width: f64,
+    pub onchange: Callback<ChartRange>,
+}
+
+/// Alto del brush
+const BRUSH_HEIGHT: f64 = 40.0;
+/// Ancho de las manijas de los bordes
+const HANDLE_WIDTH: f64 = 6.0;
+
+/// Vista general de toda la serie con la ventana visible: se arrastra la
+/// ventana (pan), sus bordes (zoom) o se hace click afuera para centrarla
+#[function_component(ChartBrush)]
+pub(crate) fn chart_brush(props: &ChartBrushProps) -> Html {
+    let svg_ref = use_node_ref();
+    let (bounds, range, min_span, width) = (props.bounds, props.range, props.min_span, props.width);
+    let seconds_per_px = bounds.span() as f64 / width.max(1.0);
+    let x = |time: i64| (time - bounds.start) as f64 / seconds_per_px.max(f64::MIN_POSITIVE);
+
+    let (start_drag, dragging) = use_drag({
+        let onchange = props.onchange.clone();
+        Callback::from(move |((handle, initial), dx): ((BrushHandle, ChartRange), i32)| {
+            let delta = (dx as f64 * seconds_per_px).round() as i64;
+            let next = match handle {
+                BrushHandle::Window => initial.pan(delta, bounds),
+                BrushHandle::Start => ChartRange::new(
+                    (initial.start + delta).max(bounds.start).min(initial.end - min_span),
+                    initial.end,
+                ),
+                BrushHandle::End => ChartRange::new(
+                    initial.start,
+                    (initial.end + delta).min(bounds.end).max(initial.start + min_span),
+                ),
+            };
+            if dx != 0 {
+                onchange.emit(next);
+            }
+        })
+    });
+
+    let drag_from = |handle: BrushHandle| {
+        let start_drag = start_drag.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            e.stop_propagation();
+            start_drag.emit(((handle, range), e.client_x()));
+        })
+    };
+
+    // Click fuera de la ventana: la centra ahí y sigue como arrastre
+    let onbackground = {
+        let svg_ref = svg_ref.clone();
+        let onchange = props.onchange.clone();
+        let start_drag = start_drag.clone();
+        Callback::from(move |e: MouseEvent| {
+            let Some(svg) = svg_ref.cast::<Element>() else {
+                return;
+            };
+            e.prevent_default();
+            let px = e.client_x() as f64 - svg.get_bounding_client_rect().left();
+            let time = bounds.start + (px * seconds_per_px).round() as i64;
+            let centered = ChartRange::new(time - range.span() / 2, time + range.span() / 2).clamp(bounds, min_span);
+            onchange.emit(centered);
+            start_drag.emit(((BrushHandle::Window, centered), e.client_x()));
+        })
+    };
+
+    let low = props.points.iter().map(|(_, v)| *v).fold(f64::INFINITY, f64::min);
+    let high = props.points.iter().map(|(_, v)| *v).fold(f64::NEG_INFINITY, f64::max);
+    let y = |value: f64| match high - low {
+        spread if spread > 0.0 => 4.0 + (high - value) / spread * (BRUSH_HEIGHT - 8.0),
+        _ => BRUSH_HEIGHT / 2.0,
+    };
+    let line = props
+        .points
+        .iter()
+        .enumerate()
+        .map(|(idx, (time, value))| format!("{}{:.2},{:.2}", if idx == 0 { "M" } else { "L" }, x(*time), y(*value)))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let (window_x, window_width) = (x(range.start), (x(range.end) - x(range.start)).max(1.0));
+
+    html! {
+        <svg
+            ref={svg_ref}
+            width={width.to_string()}
+            height={BRUSH_HEIGHT.to_string()}
+            role="img"
+            aria-label="Visible range"
+            class="block mt-2 select-none"
+        >
+            <rect
+                width={width.to_string()}
+                height={BRUSH_HEIGHT.to_string()}
+                rx="4"
+                class="fill-bg-tertiary cursor-pointer"
+                onmousedown={onbackground}
+            />
+            <path
+                d={line}
+                fill="none"
+                stroke-width="1"
+                class="stroke-text-tertiary pointer-events-none"
+            />
+            // Lo que queda fuera de la ventana se atenúa
+            <g class="fill-bg-primary pointer-events-none" fill-opacity="0.6">
+                <rect width={window_x.to_string()} height={BRUSH_HEIGHT.to_string()} />
+                <rect
+                    x={(window_x + window_width).to_string()}
+                    width={(width - window_x - window_width).max(0.0).to_string()}
+                    height={BRUSH_HEIGHT.to_string()}
+                />
+            </g>
+            <rect
+                x={window_x.to_string()}
+                width={window_width.to_string()}
+                height={BRUSH_HEIGHT.to_string()}
+                fill="transparent"
+                stroke-width="1"
+                class={classes!("stroke-border-emphasis", if dragging { "cursor-grabbing" } else { "cursor-grab" })}
+                onmousedown={drag_from(BrushHandle::Window)}
+            />
+            <rect
+                x={(window_x - HANDLE_WIDTH / 2.0).to_string()}
+                y="8"
+                width={HANDLE_WIDTH.to_string()}
+                height={(BRUSH_HEIGHT - 16.0).to_string()}
+                rx="2"
+                class="fill-border-emphasis cursor-ew-resize"
+                onmousedown={drag_from(BrushHandle::Start)}
+            />
+            <rect
+                x={(window_x + window_width - HANDLE_WIDTH / 2.0).to_string()}
+                y="8"
+                width={HANDLE_WIDTH.to_string()}
+                height={(BRUSH_HEIGHT - 16.0).to_string()}
+                rx="2"
+                class="fill-border-emphasis cursor-ew-resize"
+                onmousedown={drag_from(BrushHandle::End)}
+            />
+        </svg>
+    }
+}
@@ -0,0 +1,247 @@
+/// Segundos en un día
+const DAY: i64 = 86_400;
+
+/// Pasos de tiempo "redondos" para los ticks del eje X, en segundos. Los
+/// de 30 y 365 días eligen el paso; los ticks caen en el cambio de mes o de
+/// año calendario
+const TIME_STEPS: [i64; 15] = [
+    60,
+    5 * 60,
+    15 * 60,
+    30 * 60,
+    3_600,
+    2 * 3_600,
+    4 * 3_600,
+    6 * 3_600,
+    12 * 3_600,
+    DAY,
+    2 * DAY,
+    7 * DAY,
+    14 * DAY,
+    30 * DAY,
+    365 * DAY,
+];
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Escala lineal de un dominio de valores a un rango de píxeles
+///
+/// El rango puede estar invertido (ej: precios, donde el máximo va arriba).
+///
+/// ```
+/// use hubermann_ui::LinearScale;
+///
+/// let y = LinearScale::new((100.0, 110.0), (200.0, 0.0));
+/// assert_eq!(y.map(100.0), 200.0);
+/// assert_eq!(y.map(105.0), 100.0);
+/// assert_eq!(y.invert(0.0), 110.0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LinearScale {
+    pub domain: (f64, f64),
+    pub range: (f64, f64),
+}
+
+impl LinearScale {
+    pub fn new(domain: (f64, f64), range: (f64, f64)) -> Self {
+        Self { domain, range }
+    }
+
+    /// Valor → píxel. Un dominio vacío cae en el medio del rango
+    pub fn map(&self, value: f64) -> f64 {
+        let (d0, d1) = self.domain;
+        let (r0, r1) = self.range;
+        if d1 == d0 {
+            return (r0 + r1) / 2.0;
+        }
+        r0 + (value - d0) / (d1 - d0) * (r1 - r0)
+    }
+
+    /// Píxel → valor
+    pub fn invert(&self, pixel: f64) -> f64 {
+        let (d0, d1) = self.domain;
+        let (r0, r1) = self.range;
+        if r1 == r0 {
+            return d0;
+        }
+        d0 + (pixel - r0) / (r1 - r0) * (d1 - d0)
+    }
+}
+
+/// Paso "redondo" (1, 2 o 5 × 10^n) más cercano por arriba a `raw`
+///
+/// ```
+/// use hubermann_ui::nice_step;
+///
+/// assert_eq!(nice_step(0.72), 1.0);
+/// assert_eq!(nice_step(18.0), 20.0);
+/// assert_eq!(nice_step(340.0), 500.0);
+/// ```
+pub fn nice_step(raw: f64) -> f64 {
+    if !(raw.is_finite() && raw > 0.0) {
+        return 1.0;
+    }
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let nice = match raw / magnitude {
+        n if n <= 1.0 => 1.0,
+        n if n <= 2.0 => 2.0,
+        n if n <= 5.0 => 5.0,
+        _ => 10.0,
+    };
+    nice * magnitude
+}
+
+/// Decimales necesarios para mostrar valores múltiplos de `step`
+///
+/// ```
+/// use hubermann_ui::tick_decimals;
+///
+/// assert_eq!(tick_decimals(500.0), 0);
+/// assert_eq!(tick_decimals(0.5), 1);
+/// assert_eq!(tick_decimals(0.02), 2);
+/// ```
+pub fn tick_decimals(step: f64) -> usize {
+    if !(step.is_finite() && step > 0.0) {
+        return 0;
+    }
+    (-step.log10().floor()).max(0.0) as usize
+}
+
+/// Ticks redondos dentro de `[min, max]`, aproximadamente `count`
+///
+/// ```
+/// use hubermann_ui::nice_ticks;
+///
+/// assert_eq!(nice_ticks(0.0, 100.0, 5), vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+/// assert_eq!(nice_ticks(101.3, 104.9, 5), vec![102.0, 103.0, 104.0]);
+/// assert_eq!(nice_ticks(0.95, 1.38, 8), vec![1.0, 1.1, 1.2, 1.3]);
+/// ```
+pub fn nice_ticks(min: f64, max: f64, count: usize) -> Vec<f64> {
+    if !(min.is_finite() && max.is_finite()) || count == 0 {
+        return Vec::new();
+    }
+    if max <= min {
+        return vec![min];
+    }
+
+    let step = nice_step((max - min) / count as f64);
+    // Redondea al paso para no arrastrar errores de punto flotante (0.1 * 3)
+    let factor = 10f64.powi(tick_decimals(step) as i32);
+    let first = (min / step).ceil() as i64;
+    let last = (max / step).floor() as i64;
+
+    (first..=last)
+        .map(|idx| (idx as f64 * step * factor).round() / factor)
+        .collect()
+}
+
+/// Ticks del eje de tiempo para una serie con timestamps (unix, segundos UTC)
+///
+/// Elige un paso redondo (minutos, horas, días, semanas…) para que haya
+/// aproximadamente `count` ticks y marca el primer punto de cada tramo.
+/// Devuelve el índice del punto y su label; así los huecos de la serie
+/// (fines de semana, mercado cerrado) no dejan ticks en el aire.
+///
+/// ```
+/// use hubermann_ui::time_ticks;
+///
+/// // Velas de 1h durante dos días desde 2024-01-01 00:00 UTC
+/// let times: Vec<i64> = (0..48).map(|h| 1_704_067_200 + h * 3_600).collect();
+///
+/// assert_eq!(
+///     time_ticks(&times, 6),
+///     vec![(12, "12:00".to_string()), (24, "Jan 02".to_string()), (36, "12:00".to_string())]
+/// );
+///
+/// // Velas diarias de 2024-01-01 a 2024-04-29: un tick por mes calendario
+/// let days: Vec<i64> = (0..120).map(|d| 1_704_067_200 + d * 86_400).collect();
+///
+/// assert_eq!(
+///     time_ticks(&days, 4),
+///     vec![(31, "Feb 2024".to_string()), (60, "Mar 2024".to_string()), (91, "Apr 2024".to_string())]
+/// );
+/// ```
+pub fn time_ticks(times: &[i64], count: usize) -> Vec<(usize, String)> {
+    let (Some(first), Some(last)) = (times.first(), times.last()) else {
+        return Vec::new();
+    };
+
+    let step = time_step((last - first) / count.max(1) as i64);
+    times
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| time_bucket(pair[0], step) != time_bucket(pair[1], step))
+        .map(|(idx, pair)| (idx + 1, time_label(pair[1], step)))
+        .collect()
+}
+
+/// Primer paso de `TIME_STEPS` que cubre `raw` segundos
+fn time_step(raw: i64) -> i64 {
+    TIME_STEPS
+        .iter()
+        .copied()
+        .find(|step| *step >= raw)
+        .unwrap_or(TIME_STEPS[TIME_STEPS.len() - 1])
+}
+
+/// Tramo de un timestamp para un paso: año o mes calendario en los pasos
+/// largos, múltiplos del paso en el resto
+fn time_bucket(time: i64, step: i64) -> i64 {
+    if step >= 30 * DAY {
+        let (year, month, _) = civil_date(time);
+        match step >= 365 * DAY {
+            true => year,
+            false => year * 12 + month as i64 - 1,
+        }
+    } else {
+        time.div_euclid(step)
+    }
+}
+
+/// Label de un tick: hora dentro del día, fecha al cambiar de día, año al
+/// cambiar de año
+fn time_label(time: i64, step: i64) -> String {
+    let (year, month, day) = civil_date(time);
+    if step >= 365 * DAY {
+        year.to_string()
+    } else if step >= 30 * DAY {
+        format!("{} {}", MONTHS[month - 1], year)
+    } else if step >= DAY || time.rem_euclid(DAY) == 0 {
+        format!("{} {:02}", MONTHS[month - 1], day)
+    } else {
+        clock_time(time)
+    }
+}
+
+/// Fecha y hora completas (ej: tooltips): `Jan 05 14:30`
+///
+/// ```
+/// use hubermann_ui::format_timestamp;
+///
+/// assert_eq!(format_timestamp(1_704_101_400), "Jan 01 09:30");
+/// ```
+pub fn format_timestamp(time: i64) -> String {
+    let (_, month, day) = civil_date(time);
+    format!("{} {:02} {}", MONTHS[month - 1], day, clock_time(time))
+}
+
+fn clock_time(time: i64) -> String {
+    let seconds = time.rem_euclid(DAY);
+    format!("{:02}:{:02}", seconds / 3_600, seconds % 3_600 / 60)
+}
+
+/// (año, mes 1-12, día) de un timestamp UTC (algoritmo days-to-civil)
+fn civil_date(time: i64) -> (i64, usize, i64) {
+    let z = time.div_euclid(DAY) + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month as usize, day)
+}
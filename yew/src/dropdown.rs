@@ -19,7 +19,7 @@ use web_sys::{window, KeyboardEvent, MouseEvent};
 /// - `position`: DropdownPosition - Alineación (Left/Right)
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// let (open, set_open) = use_state(|| false);
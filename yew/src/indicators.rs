@@ -0,0 +1,391 @@
+//! Indicadores técnicos
+//!
+//! Cálculo puro (sin DOM) de los indicadores más usados. Las funciones
+//! devuelven una serie alineada con la entrada: `None` mientras el
+//! indicador no tiene suficientes datos (warm-up), así el índice i siempre
+//! corresponde a la vela/precio i. `indicator_series` la convierte en una
+//! `LineSeries` para superponerla en los charts.
+
+use crate::badge::BadgeVariant;
+use crate::chart::{Candle, LineSeries};
+
+/// Media móvil simple de `period` valores
+///
+/// ```
+/// use hubermann_ui::sma;
+///
+/// assert_eq!(sma(&[1.0, 2.0, 3.0, 4.0, 5.0], 3), vec![None, None, Some(2.0), Some(3.0), Some(4.0)]);
+/// ```
+pub fn sma(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    if period == 0 {
+        return vec![None; values.len()];
+    }
+
+    let mut sum = 0.0;
+    values
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            sum += value;
+            if idx >= period {
+                sum -= values[idx - period];
+            }
+            (idx + 1 >= period).then(|| sum / period as f64)
+        })
+        .collect()
+}
+
+/// Media móvil exponencial, arrancando con la SMA de los primeros `period`
+///
+/// ```
+/// use hubermann_ui::ema;
+///
+/// assert_eq!(ema(&[1.0, 2.0, 3.0, 4.0, 5.0], 3), vec![None, None, Some(2.0), Some(3.0), Some(4.0)]);
+/// ```
+pub fn ema(values: &[f64], period: usize) -> Vec<Option<f64>> {
+    if period == 0 || values.len() < period {
+        return vec![None; values.len()];
+    }
+
+    let alpha = 2.0 / (period as f64 + 1.0);
+    let mut current = values[..period].iter().sum::<f64>() / period as f64;
+    let mut result = vec![None; period - 1];
+    result.push(Some(current));
+    for value in &values[period..] {
+        current = alpha * value + (1.0 - alpha) * current;
+        result.push(Some(current));
+    }
+    result
+}
+
+/// EMA de una serie que a su vez tiene warm-up (ej: la línea MACD)
+fn ema_after_warmup(values: &[Option<f64>], period: usize) -> Vec<Option<f64>> {
+    let skip = values.iter().take_while(|value| value.is_none()).count();
+    let defined: Vec<f64> = values[skip..].iter().map(|value| value.unwrap_or_default()).collect();
+
+    let mut result = vec![None; skip];
+    result.extend(ema(&defined, period));
+    result
+}
+
+/// RSI de Wilder (0-100) sobre `period` cambios
+///
+/// ```
+/// use hubermann_ui::rsi;
+///
+/// assert_eq!(rsi(&[10.0, 11.0, 10.0, 11.0], 2), vec![None, None, Some(50.0), Some(75.0)]);
+/// // Solo subas: 100
+/// assert_eq!(rsi(&[1.0, 2.0, 3.0], 2)[2], Some(100.0));
+/// ```
+pub fn rsi(closes: &[f64], period: usize) -> Vec<Option<f64>> {
+    if period == 0 || closes.len() <= period {
+        return vec![None; closes.len()];
+    }
+
+    let changes: Vec<f64> = closes.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let mut gain = changes[..period].iter().map(|c| c.max(0.0)).sum::<f64>() / period as f64;
+    let mut loss = changes[..period].iter().map(|c| (-c).max(0.0)).sum::<f64>() / period as f64;
+
+    let index = |gain: f64, loss: f64| {
+        if loss == 0.0 {
+            100.0
+        } else {
+            100.0 - 100.0 / (1.0 + gain / loss)
+        }
+    };
+
+    let mut result = vec![None; period];
+    result.push(Some(index(gain, loss)));
+    for change in &changes[period..] {
+        gain = (gain * (period - 1) as f64 + change.max(0.0)) / period as f64;
+        loss = (loss * (period - 1) as f64 + (-change).max(0.0)) / period as f64;
+        result.push(Some(index(gain, loss)));
+    }
+    result
+}
+
+/// Líneas del MACD, alineadas con los precios
+#[derive(Clone, PartialEq, Debug)]
+pub struct Macd {
+    /// EMA rápida - EMA lenta
+    pub macd: Vec<Option<f64>>,
+    /// EMA de la línea MACD
+    pub signal: Vec<Option<f64>>,
+    /// MACD - señal
+    pub histogram: Vec<Option<f64>>,
+}
+
+/// MACD (típicamente 12, 26, 9)
+///
+/// ```
+/// use hubermann_ui::macd;
+///
+/// let lines = macd(&[5.0; 40], 12, 26, 9);
+/// assert_eq!(lines.macd[24], None);
+/// assert_eq!(lines.macd[25], Some(0.0));
+/// assert_eq!(lines.signal[32], None);
+/// assert_eq!(lines.histogram[39], Some(0.0));
+/// ```
+pub fn macd(closes: &[f64], fast: usize, slow: usize, signal: usize) -> Macd {
+    let fast = ema(closes, fast);
+    let slow = ema(closes, slow);
+    let line: Vec<Option<f64>> = fast
+        .iter()
+        .zip(&slow)
+        .map(|(fast, slow)| Some((*fast)? - (*slow)?))
+        .collect();
+    let signal = ema_after_warmup(&line, signal);
+    let histogram = line
+        .iter()
+        .zip(&signal)
+        .map(|(line, signal)| Some((*line)? - (*signal)?))
+        .collect();
+
+    Macd {
+        macd: line,
+        signal,
+        histogram,
+    }
+}
+
+/// Bandas de Bollinger, alineadas con los precios
+#[derive(Clone, PartialEq, Debug)]
+pub struct BollingerBands {
+    pub upper: Vec<Option<f64>>,
+    /// SMA de `period`
+    pub middle: Vec<Option<f64>>,
+    pub lower: Vec<Option<f64>>,
+}
+
+/// Bandas de Bollinger: SMA ± `width` desvíos estándar (típicamente 20, 2.0)
+///
+/// ```
+/// use hubermann_ui::bollinger;
+///
+/// let bands = bollinger(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0], 8, 2.0);
+/// assert_eq!(bands.middle[7], Some(5.0));
+/// assert_eq!(bands.upper[7], Some(9.0));
+/// assert_eq!(bands.lower[7], Some(1.0));
+/// ```
+pub fn bollinger(closes: &[f64], period: usize, width: f64) -> BollingerBands {
+    let middle = sma(closes, period);
+    let deviation: Vec<Option<f64>> = middle
+        .iter()
+        .enumerate()
+        .map(|(idx, mean)| {
+            let mean = (*mean)?;
+            let window = &closes[idx + 1 - period..=idx];
+            let variance = window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / period as f64;
+            Some(variance.sqrt())
+        })
+        .collect();
+
+    let band = |sign: f64| {
+        middle
+            .iter()
+            .zip(&deviation)
+            .map(|(mean, deviation)| Some((*mean)? + sign * width * (*deviation)?))
+            .collect()
+    };
+
+    BollingerBands {
+        upper: band(1.0),
+        lower: band(-1.0),
+        middle,
+    }
+}
+
+/// Average True Range de Wilder
+///
+/// ```
+/// use hubermann_ui::{atr, Candle};
+///
+/// let candles = vec![
+///     Candle::new(0, 10.0, 11.0, 9.0, 10.0, 0.0),
+///     Candle::new(60, 10.0, 11.0, 9.0, 10.0, 0.0),
+///     Candle::new(120, 10.0, 14.0, 10.0, 13.0, 0.0),
+/// ];
+/// assert_eq!(atr(&candles, 2), vec![None, Some(2.0), Some(3.0)]);
+/// ```
+pub fn atr(candles: &[Candle], period: usize) -> Vec<Option<f64>> {
+    if period == 0 || candles.len() < period {
+        return vec![None; candles.len()];
+    }
+
+    let true_ranges: Vec<f64> = candles
+        .iter()
+        .enumerate()
+        .map(|(idx, candle)| match idx {
+            0 => candle.high - candle.low,
+            _ => {
+                let previous = candles[idx - 1].close;
+                (candle.high - candle.low)
+                    .max((candle.high - previous).abs())
+                    .max((candle.low - previous).abs())
+            }
+        })
+        .collect();
+
+    let mut current = true_ranges[..period].iter().sum::<f64>() / period as f64;
+    let mut result = vec![None; period - 1];
+    result.push(Some(current));
+    for true_range in &true_ranges[period..] {
+        current = (current * (period - 1) as f64 + true_range) / period as f64;
+        result.push(Some(current));
+    }
+    result
+}
+
+/// VWAP acumulado sobre las velas dadas (pasar una sola sesión), con el
+/// precio típico (high + low + close) / 3
+///
+/// ```
+/// use hubermann_ui::{vwap, Candle};
+///
+/// let candles = vec![
+///     Candle::new(0, 10.0, 10.0, 10.0, 10.0, 1.0),
+///     Candle::new(60, 20.0, 20.0, 20.0, 20.0, 3.0),
+/// ];
+/// assert_eq!(vwap(&candles), vec![Some(10.0), Some(17.5)]);
+/// ```
+pub fn vwap(candles: &[Candle]) -> Vec<Option<f64>> {
+    let (mut traded, mut volume) = (0.0, 0.0);
+    candles
+        .iter()
+        .map(|candle| {
+            traded += (candle.high + candle.low + candle.close) / 3.0 * candle.volume;
+            volume += candle.volume;
+            (volume > 0.0).then(|| traded / volume)
+        })
+        .collect()
+}
+
+/// Serie de un indicador lista para superponer en `LineChart` o como
+/// `overlays` de `CandlestickChart`; saltea el warm-up
+///
+/// ```
+/// use hubermann_ui::{indicator_series, sma};
+///
+/// let times = [0, 60, 120];
+/// let series = indicator_series("SMA 2", &times, &sma(&[1.0, 2.0, 3.0], 2));
+/// assert_eq!(series.points, vec![(60, 1.5), (120, 2.5)]);
+/// ```
+pub fn indicator_series(name: impl Into<String>, times: &[i64], values: &[Option<f64>]) -> LineSeries {
+    let points = times
+        .iter()
+        .zip(values)
+        .filter_map(|(time, value)| value.map(|value| (*time, value)))
+        .collect();
+    LineSeries::new(name, points)
+}
+
+/// Badge para un RSI: > 70 sobrecompra, < 30 sobreventa
+///
+/// ```
+/// use hubermann_ui::{rsi_badge, BadgeVariant};
+///
+/// assert!(rsi_badge(72.0) == (BadgeVariant::Bearish, "overbought"));
+/// assert!(rsi_badge(25.0) == (BadgeVariant::Bullish, "oversold"));
+/// assert!(rsi_badge(50.0) == (BadgeVariant::Neutral, "neutral"));
+/// ```
+pub fn rsi_badge(rsi: f64) -> (BadgeVariant, &'static str) {
+    if rsi > 70.0 {
+        (BadgeVariant::Bearish, "overbought")
+    } else if rsi < 30.0 {
+        (BadgeVariant::Bullish, "oversold")
+    } else {
+        (BadgeVariant::Neutral, "neutral")
+    }
+}
+
+/// Badge para el histograma del MACD (MACD por encima o debajo de la señal)
+///
+/// ```
+/// use hubermann_ui::{macd_badge, BadgeVariant};
+///
+/// assert!(macd_badge(0.8) == (BadgeVariant::Bullish, "bullish"));
+/// assert!(macd_badge(-0.3) == (BadgeVariant::Bearish, "bearish"));
+/// ```
+pub fn macd_badge(histogram: f64) -> (BadgeVariant, &'static str) {
+    if histogram > 0.0 {
+        (BadgeVariant::Bullish, "bullish")
+    } else if histogram < 0.0 {
+        (BadgeVariant::Bearish, "bearish")
+    } else {
+        (BadgeVariant::Neutral, "neutral")
+    }
+}
+
+/// Badge para un precio contra sus bandas de Bollinger
+///
+/// ```
+/// use hubermann_ui::{bollinger_badge, BadgeVariant};
+///
+/// assert!(bollinger_badge(105.0, 104.0, 96.0) == (BadgeVariant::Warning, "above upper band"));
+/// assert!(bollinger_badge(100.0, 104.0, 96.0) == (BadgeVariant::Neutral, "inside bands"));
+/// ```
+pub fn bollinger_badge(price: f64, upper: f64, lower: f64) -> (BadgeVariant, &'static str) {
+    if price > upper {
+        (BadgeVariant::Warning, "above upper band")
+    } else if price < lower {
+        (BadgeVariant::Warning, "below lower band")
+    } else {
+        (BadgeVariant::Neutral, "inside bands")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candles(count: usize) -> Vec<Candle> {
+        (0..count)
+            .map(|idx| {
+                let close = 10.0 + idx as f64;
+                Candle::new(idx as i64 * 60, close - 0.5, close + 1.0, close - 1.0, close, 0.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn zero_period_is_all_warmup() {
+        let closes = [1.0, 2.0, 3.0];
+        assert_eq!(ema(&closes, 0), vec![None; 3]);
+        assert_eq!(rsi(&closes, 0), vec![None; 3]);
+        assert_eq!(atr(&candles(3), 0), vec![None; 3]);
+
+        let bands = bollinger(&closes, 0, 2.0);
+        assert_eq!(bands.upper, vec![None; 3]);
+        assert_eq!(bands.middle, vec![None; 3]);
+        assert_eq!(bands.lower, vec![None; 3]);
+    }
+
+    #[test]
+    fn fewer_values_than_period_is_all_warmup() {
+        let closes = [1.0, 2.0, 3.0];
+        assert_eq!(ema(&closes, 4), vec![None; 3]);
+        // RSI necesita `period` cambios, o sea period + 1 precios
+        assert_eq!(rsi(&closes, 3), vec![None; 3]);
+        assert_eq!(atr(&candles(3), 4), vec![None; 3]);
+
+        let bands = bollinger(&closes, 4, 2.0);
+        assert_eq!(bands.upper, vec![None; 3]);
+        assert_eq!(bands.lower, vec![None; 3]);
+    }
+
+    #[test]
+    fn empty_input_gives_empty_series() {
+        assert!(ema(&[], 3).is_empty());
+        assert!(rsi(&[], 3).is_empty());
+        assert!(atr(&[], 3).is_empty());
+        assert!(bollinger(&[], 3, 2.0).middle.is_empty());
+    }
+
+    #[test]
+    fn period_equal_to_length_has_one_value() {
+        let closes = [1.0, 2.0, 3.0];
+        assert_eq!(ema(&closes, 3), vec![None, None, Some(2.0)]);
+        assert_eq!(atr(&candles(3), 3).iter().filter(|v| v.is_some()).count(), 1);
+        assert_eq!(bollinger(&closes, 3, 2.0).middle, vec![None, None, Some(2.0)]);
+    }
+}
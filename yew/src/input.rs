@@ -22,7 +22,7 @@ use web_sys::HtmlInputElement;
 /// - `oninput`: Callback<String> - Handler cuando cambia el valor
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// let value = use_state(|| String::new());
@@ -76,18 +76,11 @@ pub fn input(props: &InputProps) -> Html {
         ("border-border-default", "focus:border-accent focus:ring-accent/20")
     };
     
-    // Label color según error
-    let label_class = if props.error.is_some() {
-        "text-text-primary"
-    } else {
-        "text-text-primary"
-    };
-    
     html! {
         <div class="w-full">
             // Label opcional
             if let Some(label) = &props.label {
-                <label class={classes!("block", "text-sm", "font-medium", "mb-1.5", label_class)}>
+                <label class="block text-sm font-medium text-text-primary mb-1.5">
                     {label}
                 </label>
             }
@@ -17,6 +17,13 @@ mod modal;
 mod loading;
 mod tooltip;
 mod dropdown;
+mod pagination;
+mod sparkline;
+mod chart;
+mod indicators;
+mod order_book;
+mod ticker_tape;
+mod format;
 
 pub use badge::*;
 pub use accordion::*;
@@ -32,3 +39,10 @@ pub use modal::*;
 pub use loading::*;
 pub use tooltip::*;
 pub use dropdown::*;
+pub use pagination::*;
+pub use sparkline::*;
+pub use chart::*;
+pub use indicators::*;
+pub use order_book::*;
+pub use ticker_tape::*;
+pub use format::*;
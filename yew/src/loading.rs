@@ -18,7 +18,7 @@ use yew::prelude::*;
 /// - `fullscreen`: bool - Si es overlay fullscreen
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// html! {
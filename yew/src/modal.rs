@@ -23,7 +23,7 @@ use web_sys::{window, KeyboardEvent};
 /// - `footer`: Option<Html> - Footer custom (default: botón Close)
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// let (show, set_show) = use_state(|| false);
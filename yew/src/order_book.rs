@@ -0,0 +1,314 @@
+use yew::prelude::*;
+
+use crate::chart::tick_decimals;
+use crate::select::{Select, SelectOption};
+
+/// Lado del libro de órdenes
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OrderSide {
+    Bid,
+    Ask,
+}
+
+impl OrderSide {
+    pub(crate) fn text_class(&self) -> &'static str {
+        match self {
+            OrderSide::Bid => "text-bullish",
+            OrderSide::Ask => "text-bearish",
+        }
+    }
+
+    fn bar_class(&self) -> &'static str {
+        match self {
+            OrderSide::Bid => "bg-bullish/10",
+            OrderSide::Ask => "bg-bearish/10",
+        }
+    }
+}
+
+/// Nivel clickeado en el `OrderBook` (para cargar una orden a ese precio)
+#[derive(Clone, PartialEq, Debug)]
+pub struct OrderBookLevel {
+    pub side: OrderSide,
+    pub price: f64,
+    pub size: f64,
+    /// Tamaño acumulado desde el mejor precio hasta este nivel
+    pub total: f64,
+}
+
+/// Agrupa niveles `(precio, tamaño)` en múltiplos de `tick`
+///
+/// Los bids se redondean hacia abajo y los asks hacia arriba (así un
+/// nivel agrupado nunca promete un precio mejor que el real). Devuelve los
+/// niveles del mejor al peor precio, sin tamaños en cero. Con `tick` <= 0
+/// solo ordena y junta precios repetidos.
+///
+/// ```
+/// use hubermann_ui::{group_levels, OrderSide};
+///
+/// let bids = [(100.24, 1.0), (100.21, 2.0), (100.18, 0.5), (99.97, 3.0)];
+/// assert_eq!(
+///     group_levels(&bids, 0.1, OrderSide::Bid),
+///     vec![(100.2, 3.0), (100.1, 0.5), (99.9, 3.0)]
+/// );
+///
+/// let asks = [(100.31, 1.0), (100.36, 2.0), (100.42, 0.0)];
+/// assert_eq!(group_levels(&asks, 0.1, OrderSide::Ask), vec![(100.4, 3.0)]);
+/// ```
+pub fn group_levels(levels: &[(f64, f64)], tick: f64, side: OrderSide) -> Vec<(f64, f64)> {
+    // Redondea a 8 decimales para no arrastrar errores de punto flotante
+    // (1004 * 0.1 = 100.40000000000001)
+    let factor = 1e8;
+    let bucket = |price: f64| {
+        if tick <= 0.0 {
+            return price;
+        }
+        let steps = match side {
+            OrderSide::Bid => (price / tick + 1e-9).floor(),
+            OrderSide::Ask => (price / tick - 1e-9).ceil(),
+        };
+        (steps * tick * factor).round() / factor
+    };
+
+    let mut grouped: Vec<(f64, f64)> = Vec::new();
+    for (price, size) in levels.iter().filter(|(_, size)| *size > 0.0) {
+        let price = bucket(*price);
+        match grouped.iter_mut().find(|(existing, _)| *existing == price) {
+            Some((_, total)) => *total += size,
+            None => grouped.push((price, *size)),
+        }
+    }
+
+    grouped.sort_by(|(a, _), (b, _)| match side {
+        OrderSide::Bid => b.total_cmp(a),
+        OrderSide::Ask => a.total_cmp(b),
+    });
+    grouped
+}
+
+/// Mejor precio real (sin agrupar) de un lado del libro
+pub(crate) fn best_price(levels: &[(f64, f64)], side: OrderSide) -> Option<f64> {
+    let prices = levels.iter().filter(|(_, size)| *size > 0.0).map(|(price, _)| *price);
+    match side {
+        OrderSide::Bid => prices.reduce(f64::max),
+        OrderSide::Ask => prices.reduce(f64::min),
+    }
+}
+
+/// Niveles agrupados con su tamaño acumulado: `(precio, tamaño, total)`
+/// del mejor al peor precio
+///
+/// Es la data que comparten `OrderBook` y `DepthChart`.
+///
+/// ```
+/// use hubermann_ui::{cumulative_levels, OrderSide};
+///
+/// let asks = [(100.4, 1.0), (100.3, 2.0), (100.5, 0.5)];
+/// assert_eq!(
+///     cumulative_levels(&asks, 0.0, OrderSide::Ask),
+///     vec![(100.3, 2.0, 2.0), (100.4, 1.0, 3.0), (100.5, 0.5, 3.5)]
+/// );
+/// ```
+pub fn cumulative_levels(levels: &[(f64, f64)], tick: f64, side: OrderSide) -> Vec<(f64, f64, f64)> {
+    group_levels(levels, tick, side)
+        .into_iter()
+        .scan(0.0, |total, (price, size)| {
+            *total += size;
+            Some((price, size, *total))
+        })
+        .collect()
+}
+
+/// OrderBook - Ladder de profundidad (DOM)
+///
+/// Asks arriba (el mejor pegado al centro), fila de spread/mid en el
+/// medio y bids abajo. Detrás de cada fila una barra en tinte
+/// bullish/bearish muestra el tamaño acumulado, en la misma escala para
+/// los dos lados. Los precios se pueden agrupar por tick (ver
+/// `group_levels`) y click en un nivel emite `onlevelclick` para cargar
+/// una orden a ese precio.
+///
+/// La agrupación es controlada: el parent guarda `grouping`; el selector
+/// aparece solo si se pasa `ongroupingchange`.
+///
+/// Respeta el visual language:
+/// - Container con border sutil y bg-secondary
+/// - Text xs en font-mono con tabular-nums
+/// - Barras de profundidad al 10% del color semántico
+/// - Hover en bg-tertiary, sin animaciones
+///
+/// # Props
+/// - `bids`: Vec<(f64, f64)> - Niveles de compra (precio, tamaño), en cualquier orden
+/// - `asks`: Vec<(f64, f64)> - Niveles de venta (precio, tamaño), en cualquier orden
+/// - `depth`: usize - Niveles por lado (default: 10)
+/// - `grouping`: f64 - Tick de agrupación (default: 0, sin agrupar)
+/// - `grouping_options`: Vec<f64> - Opciones del selector de agrupación (se agrega "None")
+/// - `ongroupingchange`: Option<Callback<f64>> - Handler del selector (sin él no se muestra)
+/// - `decimals`: usize - Decimales de precios (default: 2)
+/// - `size_decimals`: usize - Decimales de tamaños (default: 4)
+/// - `onlevelclick`: Option<Callback<OrderBookLevel>> - Click en un nivel
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use hubermann_ui::*;
+///
+/// html! {
+///     <OrderBook
+///         bids={book.bids.clone()}
+///         asks={book.asks.clone()}
+///         grouping={*grouping}
+///         grouping_options={vec![0.01, 0.1, 1.0]}
+///         ongroupingchange={Some(on_grouping)}
+///         onlevelclick={Some(Callback::from(move |level: OrderBookLevel| set_limit_price(level.price)))}
+///     />
+/// }
+/// ```
+#[derive(Properties, PartialEq)]
+pub struct OrderBookProps {
+    pub bids: Vec<(f64, f64)>,
+    pub asks: Vec<(f64, f64)>,
+    #[prop_or(10)]
+    pub depth: usize,
+    #[prop_or(0.0)]
+    pub grouping: f64,
+    #[prop_or_default]
+    pub grouping_options: Vec<f64>,
+    #[prop_or_default]
+    pub ongroupingchange: Option<Callback<f64>>,
+    #[prop_or(2)]
+    pub decimals: usize,
+    #[prop_or(4)]
+    pub size_decimals: usize,
+    #[prop_or_default]
+    pub onlevelclick: Option<Callback<OrderBookLevel>>,
+}
+
+#[function_component(OrderBook)]
+pub fn order_book(props: &OrderBookProps) -> Html {
+    let side_levels = |levels: &[(f64, f64)], side: OrderSide| {
+        let mut levels = cumulative_levels(levels, props.grouping, side);
+        levels.truncate(props.depth);
+        levels
+    };
+    let bids = side_levels(&props.bids, OrderSide::Bid);
+    let asks = side_levels(&props.asks, OrderSide::Ask);
+
+    // Misma escala para las barras de los dos lados
+    let max_total = bids
+        .last()
+        .into_iter()
+        .chain(asks.last())
+        .map(|(_, _, total)| *total)
+        .fold(0.0, f64::max);
+
+    // El spread sale de los niveles reales, no de los agrupados
+    let best_bid = best_price(&props.bids, OrderSide::Bid);
+    let best_ask = best_price(&props.asks, OrderSide::Ask);
+    let spread = best_bid.zip(best_ask).map(|(bid, ask)| {
+        let mid = (bid + ask) / 2.0;
+        (mid, ask - bid, if mid != 0.0 { (ask - bid) / mid * 100.0 } else { 0.0 })
+    });
+
+    let row = |side: OrderSide, (price, size, total): (f64, f64, f64)| {
+        let onclick = props.onlevelclick.clone().map(|callback| {
+            Callback::from(move |_: MouseEvent| {
+                callback.emit(OrderBookLevel {
+                    side,
+                    price,
+                    size,
+                    total,
+                })
+            })
+        });
+        let clickable = onclick.is_some();
+        let tag = if clickable { "button" } else { "div" };
+        let width = if max_total > 0.0 { total / max_total * 100.0 } else { 0.0 };
+
+        html! {
+            <@{tag}
+                type={clickable.then_some("button")}
+                onclick={onclick}
+                class={classes!(
+                    "relative", "grid", "grid-cols-3", "w-full", "px-3", "py-0.5", "text-right",
+                    clickable.then_some("hover:bg-bg-tertiary cursor-pointer transition-colors"),
+                )}
+            >
+                <span
+                    class={classes!("absolute", "inset-y-0", "right-0", side.bar_class())}
+                    style={format!("width: {:.2}%", width)}
+                />
+                <span class={classes!("relative", "text-left", side.text_class())}>
+                    {format!("{:.*}", props.decimals, price)}
+                </span>
+                <span class="relative text-text-secondary">{format!("{:.*}", props.size_decimals, size)}</span>
+                <span class="relative text-text-tertiary">{format!("{:.*}", props.size_decimals, total)}</span>
+            </@>
+        }
+    };
+
+    let grouping_selector = props.ongroupingchange.clone().map(|ongroupingchange| {
+        let label = |tick: f64| match tick {
+            t if t > 0.0 => format!("{:.*}", tick_decimals(t), t),
+            _ => "None".to_string(),
+        };
+        // "None" (0) siempre está y el valor actual también, así el Select
+        // nunca muestra un valor que no es una de sus opciones
+        let mut options = props.grouping_options.clone();
+        for tick in [0.0, props.grouping] {
+            if !options.contains(&tick) {
+                options.push(tick);
+            }
+        }
+        options.sort_by(f64::total_cmp);
+
+        html! {
+            <div class="w-28">
+                <Select
+                    options={options.iter().map(|tick| SelectOption::new(tick.to_string(), label(*tick))).collect::<Vec<_>>()}
+                    value={props.grouping.to_string()}
+                    onchange={Callback::from(move |value: String| {
+                        if let Ok(tick) = value.parse::<f64>() {
+                            ongroupingchange.emit(tick);
+                        }
+                    })}
+                />
+            </div>
+        }
+    });
+
+    html! {
+        <div class="border border-border-default rounded-md bg-bg-secondary py-2 text-xs">
+            if let Some(selector) = grouping_selector {
+                <div class="flex items-center justify-between px-3 pb-2">
+                    <span class="text-text-tertiary">{"Grouping"}</span>
+                    {selector}
+                </div>
+            }
+            <div class="grid grid-cols-3 px-3 pb-1 text-right font-medium text-text-tertiary uppercase tracking-wide">
+                <span class="text-left">{"Price"}</span>
+                <span>{"Size"}</span>
+                <span>{"Total"}</span>
+            </div>
+
+            <div class="font-mono tabular-nums">
+                // Asks: el mejor queda abajo, pegado al spread
+                { for asks.iter().rev().map(|level| row(OrderSide::Ask, *level)) }
+
+                <div class="flex items-baseline justify-between px-3 py-1.5 my-1 border-y border-border-subtle">
+                    if let Some((mid, spread, spread_pct)) = spread {
+                        <span class="text-sm font-semibold text-text-primary">
+                            {format!("{:.*}", props.decimals + 1, mid)}
+                        </span>
+                        <span class="text-text-tertiary">
+                            {format!("Spread {:.*} ({:.3}%)", props.decimals, spread, spread_pct)}
+                        </span>
+                    } else {
+                        <span class="text-text-tertiary">{"No spread"}</span>
+                    }
+                </div>
+
+                { for bids.iter().map(|level| row(OrderSide::Bid, *level)) }
+            </div>
+        </div>
+    }
+}
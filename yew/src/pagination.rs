@@ -0,0 +1,210 @@
+use yew::prelude::*;
+
+use crate::select::{Select, SelectOption};
+
+/// Pagination - Navegación entre páginas de datos
+///
+/// Muestra el rango actual ("26–50 of 1234"), botones prev/next, números
+/// de página con elipsis y un selector de tamaño de página construido
+/// sobre `Select`. Es controlado: el parent guarda `page` y `page_size`.
+///
+/// Respeta el visual language:
+/// - Botones compactos (text-sm, rounded)
+/// - Página activa con bg-tertiary + border-accent (igual que Tabs)
+/// - Info de rango en text-xs tertiary
+///
+/// # Props
+/// - `page`: usize - Página actual (empieza en 1)
+/// - `page_size`: usize - Filas por página
+/// - `total`: usize - Total de filas
+/// - `page_size_options`: Vec<usize> - Opciones del selector (default: 10/25/50/100)
+/// - `onpagechange`: Callback<usize> - Handler cuando cambia la página
+/// - `onpagesizechange`: Option<Callback<usize>> - Handler del selector (sin él no se muestra)
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use hubermann_ui::*;
+///
+/// let page = use_state(|| 1usize);
+/// let page_size = use_state(|| 25usize);
+///
+/// html! {
+///     <Pagination
+///         page={*page}
+///         page_size={*page_size}
+///         total={1234}
+///         onpagechange={{
+///             let page = page.clone();
+///             Callback::from(move |p| page.set(p))
+///         }}
+///         onpagesizechange={Some({
+///             let page_size = page_size.clone();
+///             Callback::from(move |s| page_size.set(s))
+///         })}
+///     />
+/// }
+/// ```
+#[derive(Properties, PartialEq)]
+pub struct PaginationProps {
+    pub page: usize,
+    pub page_size: usize,
+    pub total: usize,
+    #[prop_or_else(default_page_size_options)]
+    pub page_size_options: Vec<usize>,
+    pub onpagechange: Callback<usize>,
+    #[prop_or_default]
+    pub onpagesizechange: Option<Callback<usize>>,
+}
+
+pub(crate) fn default_page_size_options() -> Vec<usize> {
+    vec![10, 25, 50, 100]
+}
+
+/// Cantidad de páginas (mínimo 1, aunque no haya filas)
+pub fn page_count(total: usize, page_size: usize) -> usize {
+    if page_size == 0 {
+        return 1;
+    }
+    total.div_ceil(page_size).max(1)
+}
+
+/// Números de página a mostrar; `None` representa una elipsis
+///
+/// Siempre muestra la primera, la última y las vecinas de la actual.
+///
+/// ```
+/// use hubermann_ui::pagination_items;
+///
+/// assert_eq!(pagination_items(1, 5), vec![Some(1), Some(2), Some(3), Some(4), Some(5)]);
+/// assert_eq!(
+///     pagination_items(10, 20),
+///     vec![Some(1), None, Some(9), Some(10), Some(11), None, Some(20)]
+/// );
+/// assert_eq!(
+///     pagination_items(2, 20),
+///     vec![Some(1), Some(2), Some(3), Some(4), Some(5), None, Some(20)]
+/// );
+/// ```
+pub fn pagination_items(page: usize, total_pages: usize) -> Vec<Option<usize>> {
+    if total_pages <= 7 {
+        return (1..=total_pages).map(Some).collect();
+    }
+
+    let page = page.clamp(1, total_pages);
+    // Ventana de páginas vecinas entre la primera y la última
+    let (start, end) = if page <= 4 {
+        (2, 5)
+    } else if page >= total_pages - 3 {
+        (total_pages - 4, total_pages - 1)
+    } else {
+        (page - 1, page + 1)
+    };
+
+    let mut items = vec![Some(1)];
+    if start > 2 {
+        items.push(None);
+    }
+    items.extend((start..=end).map(Some));
+    if end < total_pages - 1 {
+        items.push(None);
+    }
+    items.push(Some(total_pages));
+    items
+}
+
+#[function_component(Pagination)]
+pub fn pagination(props: &PaginationProps) -> Html {
+    let total_pages = page_count(props.total, props.page_size);
+    let page = props.page.clamp(1, total_pages);
+
+    let first_row = if props.total == 0 { 0 } else { (page - 1) * props.page_size + 1 };
+    let last_row = (page * props.page_size).min(props.total);
+
+    let go_to = |target: usize| {
+        let onpagechange = props.onpagechange.clone();
+        Callback::from(move |_: MouseEvent| onpagechange.emit(target))
+    };
+
+    let nav_class = "px-2 py-1 rounded text-sm text-text-tertiary hover:bg-bg-tertiary hover:text-text-primary transition-colors disabled:opacity-50 disabled:cursor-not-allowed disabled:hover:bg-transparent";
+
+    html! {
+        <div class="flex flex-wrap items-center justify-between gap-3 px-3 py-2">
+            // Rango actual
+            <span class="text-xs text-text-tertiary">
+                {format!("{}–{} of {}", first_row, last_row, props.total)}
+            </span>
+
+            // Páginas
+            <nav aria-label="Pagination" class="flex items-center gap-1">
+                <button
+                    type="button"
+                    aria-label="Previous page"
+                    disabled={page <= 1}
+                    onclick={go_to(page.saturating_sub(1).max(1))}
+                    class={nav_class}
+                >
+                    {"‹"}
+                </button>
+
+                {pagination_items(page, total_pages).into_iter().map(|item| {
+                    match item {
+                        Some(number) if number == page => html! {
+                            <button
+                                type="button"
+                                aria-current="page"
+                                class="min-w-[2rem] px-2 py-1 rounded text-sm font-medium bg-bg-tertiary text-text-primary border-b-2 border-accent"
+                            >
+                                {number}
+                            </button>
+                        },
+                        Some(number) => html! {
+                            <button
+                                type="button"
+                                onclick={go_to(number)}
+                                class="min-w-[2rem] px-2 py-1 rounded text-sm text-text-tertiary hover:bg-bg-tertiary/50 hover:text-text-primary transition-colors"
+                            >
+                                {number}
+                            </button>
+                        },
+                        None => html! {
+                            <span class="px-1 text-sm text-text-muted">{"…"}</span>
+                        },
+                    }
+                }).collect::<Html>()}
+
+                <button
+                    type="button"
+                    aria-label="Next page"
+                    disabled={page >= total_pages}
+                    onclick={go_to((page + 1).min(total_pages))}
+                    class={nav_class}
+                >
+                    {"›"}
+                </button>
+            </nav>
+
+            // Selector de tamaño de página
+            if let Some(onpagesizechange) = &props.onpagesizechange {
+                <div class="flex items-center gap-2">
+                    <span class="text-xs text-text-tertiary">{"Rows"}</span>
+                    <div class="w-20">
+                        <Select
+                            options={props.page_size_options.iter().map(|size| {
+                                SelectOption::new(size.to_string(), size.to_string())
+                            }).collect::<Vec<_>>()}
+                            value={props.page_size.to_string()}
+                            onchange={{
+                                let onpagesizechange = onpagesizechange.clone();
+                                Callback::from(move |value: String| {
+                                    if let Ok(size) = value.parse::<usize>() {
+                                        onpagesizechange.emit(size);
+                                    }
+                                })
+                            }}
+                        />
+                    </div>
+                </div>
+            }
+        </div>
+    }
+}
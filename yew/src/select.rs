@@ -22,7 +22,7 @@ use web_sys::HtmlSelectElement;
 /// - `onchange`: Callback<String> - Handler cuando cambia la selección
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// let value = use_state(|| String::new());
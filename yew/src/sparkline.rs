@@ -0,0 +1,200 @@
+use yew::prelude::*;
+
+use crate::stats_card::ChangeType;
+
+/// Sparkline - Mini gráfico de tendencia en SVG
+///
+/// Línea simple para mostrar la tendencia de una serie sin ejes ni
+/// labels: debajo de un `StatsCard`, en una celda de `Table` o inline en
+/// texto. El color sale de `ChangeType` (bullish/bearish/neutral); si no
+/// se pasa, se elige comparando el último valor contra `baseline` (o
+/// contra el primero).
+///
+/// Respeta el visual language:
+/// - Stroke 1.5px en colores semánticos
+/// - Área opcional con el mismo color al 10%
+/// - Baseline punteada en border-emphasis
+/// - Sin ejes, grid ni animaciones
+///
+/// # Props
+/// - `data`: Vec<f64> - Serie de valores (en orden temporal)
+/// - `baseline`: Option<f64> - Línea de referencia (ej: cierre anterior)
+/// - `area`: bool - Relleno debajo de la línea (default: false)
+/// - `last_point`: bool - Punto en el último valor (default: true)
+/// - `change_type`: Option<ChangeType> - Color (default: según la tendencia)
+/// - `width`: f64 - Ancho en px (default: 120)
+/// - `height`: f64 - Alto en px (default: 32)
+/// - `fluid`: bool - Ocupa todo el ancho disponible (default: false)
+/// - `label`: Option<String> - Descripción para lectores de pantalla
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use hubermann_ui::*;
+///
+/// html! {
+///     <Sparkline
+///         data={vec![182.1, 183.4, 181.9, 184.2, 185.0]}
+///         baseline={Some(182.5)}
+///         area={true}
+///     />
+/// }
+/// ```
+#[derive(Properties, PartialEq)]
+pub struct SparklineProps {
+    pub data: Vec<f64>,
+    #[prop_or_default]
+    pub baseline: Option<f64>,
+    #[prop_or(false)]
+    pub area: bool,
+    #[prop_or(true)]
+    pub last_point: bool,
+    #[prop_or_default]
+    pub change_type: Option<ChangeType>,
+    #[prop_or(120.0)]
+    pub width: f64,
+    #[prop_or(32.0)]
+    pub height: f64,
+    #[prop_or(false)]
+    pub fluid: bool,
+    #[prop_or_default]
+    pub label: Option<String>,
+}
+
+/// Margen interno para que el stroke y el punto no se corten
+const PADDING: f64 = 3.0;
+
+/// Coordenadas SVG de cada valor dentro de `width` x `height`
+///
+/// El rango vertical incluye la baseline para que siempre sea visible.
+///
+/// ```
+/// use hubermann_ui::sparkline_points;
+///
+/// let points = sparkline_points(&[1.0, 3.0, 2.0], None, 106.0, 26.0);
+/// assert_eq!(points, vec![(3.0, 23.0), (53.0, 3.0), (103.0, 13.0)]);
+///
+/// // Serie plana: línea en el medio
+/// assert_eq!(sparkline_points(&[5.0, 5.0], None, 106.0, 26.0)[0].1, 13.0);
+/// ```
+pub fn sparkline_points(data: &[f64], baseline: Option<f64>, width: f64, height: f64) -> Vec<(f64, f64)> {
+    let y = vertical_scale(data, baseline, height);
+    let step = match data.len() {
+        0 | 1 => 0.0,
+        n => (width - 2.0 * PADDING) / (n - 1) as f64,
+    };
+
+    data.iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            let x = match data.len() {
+                1 => width / 2.0,
+                _ => PADDING + idx as f64 * step,
+            };
+            (x, y(*value))
+        })
+        .collect()
+}
+
+/// Valor → coordenada y, con el rango de la serie más la baseline
+fn vertical_scale(data: &[f64], baseline: Option<f64>, height: f64) -> impl Fn(f64) -> f64 {
+    let values = data.iter().chain(baseline.iter()).copied();
+    let min = values.clone().fold(f64::INFINITY, f64::min);
+    let max = values.fold(f64::NEG_INFINITY, f64::max);
+
+    move |value| match max - min {
+        range if range > 0.0 => PADDING + (max - value) / range * (height - 2.0 * PADDING),
+        _ => height / 2.0,
+    }
+}
+
+/// Tendencia de la serie: último valor contra la baseline (o el primero)
+pub(crate) fn sparkline_trend(data: &[f64], baseline: Option<f64>) -> ChangeType {
+    match (data.first(), data.last()) {
+        (Some(first), Some(last)) => ChangeType::from_delta(last - baseline.unwrap_or(*first)),
+        _ => ChangeType::Neutral,
+    }
+}
+
+#[function_component(Sparkline)]
+pub fn sparkline(props: &SparklineProps) -> Html {
+    let (width, height) = (props.width, props.height);
+    let points = sparkline_points(&props.data, props.baseline, width, height);
+    let change_type = props
+        .change_type
+        .clone()
+        .unwrap_or_else(|| sparkline_trend(&props.data, props.baseline));
+
+    let line = points
+        .iter()
+        .enumerate()
+        .map(|(idx, (x, y))| format!("{}{:.2},{:.2}", if idx == 0 { "M" } else { "L" }, x, y))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // El área cierra la línea contra el borde inferior
+    let area = match (props.area, points.first(), points.last()) {
+        (true, Some((first_x, _)), Some((last_x, _))) => Some(format!(
+            "{} L{:.2},{:.2} L{:.2},{:.2} Z",
+            line, last_x, height, first_x, height
+        )),
+        _ => None,
+    };
+
+    let baseline_y = props
+        .baseline
+        .map(|baseline| vertical_scale(&props.data, props.baseline, height)(baseline));
+
+    // Con `fluid` el SVG se estira: los strokes no escalan y el punto es un
+    // segmento de largo cero con punta redonda para que siga siendo circular
+    let (svg_width, svg_class) = if props.fluid {
+        (None, "block w-full")
+    } else {
+        (Some(width.to_string()), "inline-block")
+    };
+
+    html! {
+        <svg
+            width={svg_width}
+            height={height.to_string()}
+            viewBox={format!("0 0 {} {}", width, height)}
+            preserveAspectRatio="none"
+            role="img"
+            aria-label={props.label.clone().unwrap_or_else(|| "Trend".to_string())}
+            class={classes!(svg_class, "overflow-visible", change_type.text_class())}
+        >
+            if let Some(y) = baseline_y {
+                <line
+                    x1="0"
+                    y1={format!("{:.2}", y)}
+                    x2={width.to_string()}
+                    y2={format!("{:.2}", y)}
+                    stroke-width="1"
+                    stroke-dasharray="2 2"
+                    vector-effect="non-scaling-stroke"
+                    class="stroke-border-emphasis"
+                />
+            }
+            if let Some(area) = area {
+                <path d={area} fill="currentColor" fill-opacity="0.1" stroke="none" />
+            }
+            <path
+                d={line}
+                fill="none"
+                stroke="currentColor"
+                stroke-width="1.5"
+                stroke-linejoin="round"
+                stroke-linecap="round"
+                vector-effect="non-scaling-stroke"
+            />
+            if let (true, Some((x, y))) = (props.last_point, points.last()) {
+                <path
+                    d={format!("M{:.2},{:.2} h0", x, y)}
+                    stroke="currentColor"
+                    stroke-width="5"
+                    stroke-linecap="round"
+                    vector-effect="non-scaling-stroke"
+                />
+            }
+        </svg>
+    }
+}
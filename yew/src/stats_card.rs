@@ -1,10 +1,17 @@
 use yew::prelude::*;
 
+use crate::format::NumberFormat;
+use crate::sparkline::Sparkline;
+
 /// StatsCard - Tarjeta para métricas clave
 ///
 /// Muestra una métrica importante con su cambio porcentual.
 /// Ideal para KPIs, portfolio value, P&L, etc.
 ///
+/// El valor y el cambio pueden venir ya formateados (`value`, `change`) o
+/// crudos (`amount` con su `format`, `delta` en %): así la app no repite
+/// el formato de "$45,231.89" o "+12.5%" en cada card.
+///
 /// Respeta el visual language:
 /// - Title xs uppercase (12px)
 /// - Value 2xl bold (24px)
@@ -13,14 +20,19 @@ use yew::prelude::*;
 ///
 /// # Props
 /// - `title`: String - Título de la métrica
-/// - `value`: String - Valor principal
-/// - `change`: Option<String> - Cambio porcentual
-/// - `change_type`: Option<ChangeType> - Tipo de cambio (bullish/bearish/neutral)
+/// - `value`: String - Valor principal ya formateado
+/// - `amount`: Option<f64> - Valor principal crudo (reemplaza a `value`)
+/// - `format`: NumberFormat - Formato de `amount` y locale de `delta` (default: 2 decimales)
+/// - `change`: Option<String> - Cambio porcentual ya formateado
+/// - `delta`: Option<f64> - Cambio porcentual crudo (reemplaza a `change`)
+/// - `change_type`: Option<ChangeType> - Tipo de cambio (bullish/bearish/neutral);
+///   con `delta` se deduce del signo si no se pasa
 /// - `subtitle`: Option<String> - Info adicional (ej: "vs last month")
 /// - `elevated`: bool - Si usa estilo elevated
+/// - `sparkline`: Option<Vec<f64>> - Serie para un `Sparkline` debajo del valor
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// html! {
@@ -30,21 +42,41 @@ use yew::prelude::*;
 ///         change={Some("+12.5%".to_string())}
 ///         change_type={Some(ChangeType::Bullish)}
 ///         subtitle={Some("vs last month".to_string())}
+///         sparkline={Some(vec![40_120.0, 41_980.5, 43_002.1, 45_231.89])}
+///     />
+/// }
+///
+/// // Con valores crudos
+/// html! {
+///     <StatsCard
+///         title="Total Portfolio"
+///         amount={Some(45_231.894)}
+///         format={NumberFormat::currency("$", 2).with_locale(Locale::EsAr)}
+///         delta={Some(12.5)}
 ///     />
 /// }
 /// ```
 #[derive(Properties, PartialEq)]
 pub struct StatsCardProps {
     pub title: String,
+    #[prop_or_default]
     pub value: String,
     #[prop_or_default]
+    pub amount: Option<f64>,
+    #[prop_or_else(|| NumberFormat::new(2))]
+    pub format: NumberFormat,
+    #[prop_or_default]
     pub change: Option<String>,
     #[prop_or_default]
+    pub delta: Option<f64>,
+    #[prop_or_default]
     pub change_type: Option<ChangeType>,
     #[prop_or_default]
     pub subtitle: Option<String>,
     #[prop_or(false)]
     pub elevated: bool,
+    #[prop_or_default]
+    pub sparkline: Option<Vec<f64>>,
 }
 
 #[derive(Clone, PartialEq)]
@@ -55,7 +87,36 @@ pub enum ChangeType {
 }
 
 impl ChangeType {
-    fn classes(&self) -> &'static str {
+    /// Tipo de cambio según el signo de una variación
+    ///
+    /// ```
+    /// use hubermann_ui::ChangeType;
+    ///
+    /// assert!(ChangeType::from_delta(1.5) == ChangeType::Bullish);
+    /// assert!(ChangeType::from_delta(-0.2) == ChangeType::Bearish);
+    /// assert!(ChangeType::from_delta(0.0) == ChangeType::Neutral);
+    /// ```
+    pub fn from_delta(delta: f64) -> Self {
+        if delta > 0.0 {
+            ChangeType::Bullish
+        } else if delta < 0.0 {
+            ChangeType::Bearish
+        } else {
+            ChangeType::Neutral
+        }
+    }
+
+    /// Color de texto (y `currentColor` en SVG)
+    pub(crate) fn text_class(&self) -> &'static str {
+        match self {
+            ChangeType::Bullish => "text-bullish",
+            ChangeType::Bearish => "text-bearish",
+            ChangeType::Neutral => "text-neutral",
+        }
+    }
+
+    /// Badge de cambio: fondo al 10%, texto y border del color semántico
+    pub(crate) fn classes(&self) -> &'static str {
         match self {
             ChangeType::Bullish => "bg-bullish/10 text-bullish border-bullish/30",
             ChangeType::Bearish => "bg-bearish/10 text-bearish border-bearish/30",
@@ -63,7 +124,7 @@ impl ChangeType {
         }
     }
 
-    fn arrow(&self) -> &'static str {
+    pub(crate) fn arrow(&self) -> &'static str {
         match self {
             ChangeType::Bullish => "↑",
             ChangeType::Bearish => "↓",
@@ -80,6 +141,22 @@ pub fn stats_card(props: &StatsCardProps) -> Html {
         ("bg-bg-secondary", "border-border-default", "")
     };
 
+    let value = match props.amount {
+        Some(amount) => props.format.format(amount),
+        None => props.value.clone(),
+    };
+    let delta_format = NumberFormat::change(1).with_locale(props.format.locale);
+    let change = props
+        .change
+        .clone()
+        .or_else(|| props.delta.map(|delta| delta_format.format(delta)));
+    let change_type = props.change_type.clone().or_else(|| {
+        props.delta.map(|delta| match delta_format.rounds_to_zero(delta) {
+            true => ChangeType::Neutral,
+            false => ChangeType::from_delta(delta),
+        })
+    });
+
     html! {
         <div class={classes!(
             "border",
@@ -96,13 +173,26 @@ pub fn stats_card(props: &StatsCardProps) -> Html {
 
             // Value
             <p class="text-2xl font-semibold text-text-primary mb-2">
-                {&props.value}
+                {value}
             </p>
 
+            // Tendencia
+            if let Some(data) = &props.sparkline {
+                <div class="mb-2">
+                    <Sparkline
+                        data={data.clone()}
+                        change_type={change_type.clone()}
+                        area={true}
+                        fluid={true}
+                        height={40.0}
+                    />
+                </div>
+            }
+
             // Change + subtitle
-            if props.change.is_some() || props.subtitle.is_some() {
+            if change.is_some() || props.subtitle.is_some() {
                 <div class="flex items-center gap-2">
-                    {if let (Some(change), Some(change_type)) = (&props.change, &props.change_type) {
+                    {if let (Some(change), Some(change_type)) = (&change, &change_type) {
                         let change_classes = change_type.classes();
                         let arrow = change_type.arrow();
 
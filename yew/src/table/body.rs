@@ -0,0 +1,250 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::column::TableColumn;
+use super::detail::{detail_row, expand_cell, RowExpansion};
+use super::edit::{cell_editor, edit_triggers, EditingCell};
+use super::flash::{flash_attrs, FlashTracker};
+use super::group::{group_row, DisplayRow};
+use super::navigation::GridNavigation;
+use super::resize::{join_styles, FrozenColumns};
+use super::selection::{select_cell, RowSelection};
+use super::sort::SortKey;
+use super::virtualize::VirtualWindow;
+use super::TableProps;
+
+/// Posición de una fila dentro del body
+pub(crate) struct BodyRow {
+    /// Índice en las filas del body (grupos, datos y detalles)
+    pub index: usize,
+    /// Ref de la primera fila montada (para medir su alto), si es esta
+    pub node_ref: NodeRef,
+    /// Borde inferior (todas menos la última)
+    pub border_class: &'static str,
+}
+
+/// Lo que comparten las filas del body en un render
+pub(crate) struct TableBody<'a, T: PartialEq + 'static> {
+    pub props: &'a TableProps<T>,
+    /// Columnas visibles en el orden del layout
+    pub columns: &'a [TableColumn<T>],
+    /// Índices de `rows` de las filas mostradas, en orden
+    pub order: &'a [usize],
+    /// Key de cada fila de `rows`
+    pub keys: &'a [String],
+    pub frozen: &'a FrozenColumns,
+    pub grid: &'a GridNavigation,
+    pub selection: &'a RowSelection,
+    pub expansion: &'a RowExpansion,
+    pub toggled_groups: &'a UseStateHandle<HashSet<String>>,
+    pub editing: &'a UseStateHandle<Option<EditingCell>>,
+    pub editor_ref: &'a NodeRef,
+    pub flash_tracker: &'a RefCell<FlashTracker>,
+}
+
+impl<T: PartialEq + 'static> TableBody<'_, T> {
+    /// Filas de `display` dentro de la ventana montada
+    pub fn rows(&self, display: &[DisplayRow], window: &VirtualWindow) -> Html {
+        let rows = display[window.range.clone()]
+            .iter()
+            .zip(window.range.clone())
+            .map(|(entry, display_idx)| {
+                let row = BodyRow {
+                    index: display_idx,
+                    node_ref: if display_idx == window.range.start {
+                        window.first_row_ref.clone()
+                    } else {
+                        NodeRef::default()
+                    },
+                    border_class: if display_idx < display.len() - 1 {
+                        "border-b border-border-subtle"
+                    } else {
+                        ""
+                    },
+                };
+
+                match entry {
+                    DisplayRow::Data(position) => self.data_row(row, *position),
+                    DisplayRow::Detail(position) => {
+                        let row_idx = self.order[*position];
+                        let content = self
+                            .props
+                            .row_detail
+                            .as_ref()
+                            .map(|detail| detail.render(&self.props.rows[row_idx]))
+                            .unwrap_or_default();
+                        detail_row(row, &self.keys[row_idx], content, self.column_count(), self.grid)
+                    }
+                    DisplayRow::Group(group) => {
+                        group_row(row, group, self.columns, self.toggled_groups, self.frozen, self.grid)
+                    }
+                }
+            })
+            .collect::<Html>();
+        self.flash_tracker.borrow_mut().finish();
+        rows
+    }
+
+    fn column_count(&self) -> usize {
+        self.columns.len() + self.frozen.leading()
+    }
+
+    /// Fila de datos en `position` del orden visible
+    fn data_row(&self, row: BodyRow, position: usize) -> Html {
+        let props = self.props;
+        let row_idx = self.order[position];
+        let key = &self.keys[row_idx];
+        let selectable = self.selection.is_enabled();
+        let expandable = self.expansion.enabled;
+        let is_selected = self.selection.is_selected(key);
+        let select_row = self.selection.select_row(position);
+
+        let hover_class = if props.hoverable {
+            "hover:bg-bg-tertiary transition-colors"
+        } else {
+            ""
+        };
+        // Fondo opaco de las celdas congeladas (sigue el hover de la fila)
+        let background = if props.hoverable {
+            "bg-bg-secondary group-hover:bg-bg-tertiary"
+        } else {
+            "bg-bg-secondary"
+        };
+
+        // Enter: celda de selección/expansión, onrowactivate o alternar el detalle
+        let activate: Rc<dyn Fn(usize)> = {
+            let select_row = select_row.clone();
+            let toggle = self.expansion.toggle(key, None);
+            let onrowactivate = props.onrowactivate.clone();
+            let key = key.clone();
+            let expand_cell = usize::from(selectable);
+            Rc::new(move |cell_idx: usize| match &onrowactivate {
+                _ if selectable && cell_idx == 0 => select_row(false),
+                _ if expandable && cell_idx == expand_cell => toggle(),
+                Some(callback) => callback.emit(key.clone()),
+                None if expandable => toggle(),
+                None => {}
+            })
+        };
+        let onkeydown = self
+            .grid
+            .keydown(row.index, Some(activate), selectable.then(|| select_row.clone()));
+        let expand_idx = usize::from(selectable);
+
+        html! {
+            <tr
+                key={key.clone()}
+                ref={row.node_ref}
+                aria-rowindex={self.grid.row_index(row.index)}
+                aria-selected={selectable.then(|| is_selected.to_string())}
+                onkeydown={onkeydown}
+                class={classes!("group", row.border_class, hover_class, is_selected.then_some("bg-accent/10"))}
+            >
+                if selectable {
+                    {select_cell(
+                        self.selection,
+                        is_selected,
+                        select_row,
+                        self.grid.cell(row.index, 0),
+                        self.frozen.cell(0, background),
+                    )}
+                }
+                if expandable {
+                    {expand_cell(
+                        self.expansion,
+                        key,
+                        self.grid.cell(row.index, expand_idx),
+                        self.frozen.cell(expand_idx, background),
+                    )}
+                }
+                {self.columns.iter().enumerate().map(|(col_idx, column)| {
+                    self.data_cell(row.index, row_idx, col_idx, column, background)
+                }).collect::<Html>()}
+            </tr>
+        }
+    }
+
+    /// Celda de datos: contenido (o editor), flash de cambios y celda del grid
+    fn data_cell(
+        &self,
+        display_idx: usize,
+        row_idx: usize,
+        col_idx: usize,
+        column: &TableColumn<T>,
+        background: &'static str,
+    ) -> Html {
+        let props = self.props;
+        let row = &props.rows[row_idx];
+        let key = &self.keys[row_idx];
+        let cell = column.render(row);
+
+        let flash = match props.flash_changes {
+            true => match column.cell_sort_key(row, &cell) {
+                SortKey::Number(value) => self.flash_tracker.borrow_mut().track(key, &column.id, value),
+                _ => None,
+            },
+            false => None,
+        };
+        let (cell_key, flash_class, flash_style) = flash_attrs(&column.id, flash.as_ref(), props.flash_duration);
+
+        let cell_idx = self.frozen.leading() + col_idx;
+        let (frozen_class, frozen_style) = self.frozen.cell(cell_idx, background);
+
+        let editing_cell = self
+            .editing
+            .as_ref()
+            .filter(|editing| editing.row_key == *key && editing.column_id == column.id);
+        let editable = column.editor.is_some() && props.oncelledit.is_some();
+
+        // Doble click o Enter abren el editor
+        let (ondblclick, onkeydown) = match (editable, editing_cell) {
+            (true, None) => {
+                let (ondblclick, onkeydown) = edit_triggers(
+                    self.editing,
+                    EditingCell {
+                        row_key: key.clone(),
+                        column_id: column.id.clone(),
+                        value: column.edit_value(row),
+                        error: None,
+                    },
+                );
+                (Some(ondblclick), Some(onkeydown))
+            }
+            _ => (None, None),
+        };
+
+        let content = match editing_cell {
+            Some(editing_cell) => cell_editor(column, editing_cell, self.editing, &props.oncelledit, self.editor_ref),
+            None => column.render_content(row, &cell),
+        };
+
+        let grid = self.grid.cell(display_idx, cell_idx);
+
+        html! {
+            <td
+                key={cell_key}
+                role={grid.role}
+                tabindex={grid.tabindex.or(editable.then_some("0"))}
+                aria-colindex={grid.colindex}
+                onfocusin={grid.onfocusin}
+                ondblclick={ondblclick}
+                onkeydown={onkeydown}
+                style={join_styles(flash_style, frozen_style)}
+                class={classes!(
+                    "px-3",
+                    if editing_cell.is_some() { "py-1" } else { "py-3" },
+                    column.align.class(),
+                    (props.resizable && editing_cell.is_none()).then_some("truncate"),
+                    (editable && editing_cell.is_none()).then_some("cursor-text"),
+                    flash_class,
+                    frozen_class
+                )}
+            >
+                {content}
+            </td>
+        }
+    }
+}
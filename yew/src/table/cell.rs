@@ -0,0 +1,270 @@
+use yew::prelude::*;
+
+use super::sort::SortKey;
+use crate::format::{Locale, NumberFormat};
+use crate::sparkline::Sparkline;
+
+/// Fila de la tabla
+///
+/// Tipo de fila para tablas armadas celda por celda (sin struct de dominio).
+/// Se usa junto con `TableColumn::from_headers`.
+#[derive(Clone, PartialEq)]
+pub struct TableRow {
+    pub cells: Vec<TableCell>,
+    /// Identidad estable opcional (ver `TableRowKey::from_row`)
+    pub key: Option<String>,
+}
+
+impl TableRow {
+    pub fn new(cells: Vec<TableCell>) -> Self {
+        Self { cells, key: None }
+    }
+
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+}
+
+/// Celda individual con tipo y estilo
+#[derive(Clone, PartialEq)]
+pub struct TableCell {
+    pub content: String,
+    pub cell_type: TableCellType,
+    /// Clave de orden explícita (si es None se deriva del content)
+    pub sort_key: Option<SortKey>,
+    /// Valor para exportar a CSV/TSV (si es None se usa el content)
+    pub export_value: Option<String>,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum TableCellType {
+    /// Texto normal
+    Text,
+    /// Texto destacado (bold)
+    Primary,
+    /// Texto secundario (color muted)
+    Secondary,
+    /// Cambio con color semántico
+    Change(TableChangeType),
+    /// Número alineado a la derecha con cifras tabulares (font mono).
+    /// Con `decimal_align` se reservan esos caracteres (`ch`) para la parte
+    /// decimal, así el separador decimal (el del `Locale` del formato) queda
+    /// alineado aunque varíe la precisión.
+    Numeric {
+        decimal_align: Option<usize>,
+        decimal_separator: char,
+    },
+    /// Contenido custom HTML
+    Custom(Html),
+}
+
+#[derive(Clone, PartialEq)]
+pub enum TableChangeType {
+    Bullish,
+    Bearish,
+    Neutral,
+}
+
+impl TableCell {
+    pub fn text(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            cell_type: TableCellType::Text,
+            sort_key: None,
+            export_value: None,
+        }
+    }
+
+    pub fn primary(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            cell_type: TableCellType::Primary,
+            sort_key: None,
+            export_value: None,
+        }
+    }
+
+    pub fn secondary(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            cell_type: TableCellType::Secondary,
+            sort_key: None,
+            export_value: None,
+        }
+    }
+
+    pub fn change(content: impl Into<String>, change_type: TableChangeType) -> Self {
+        Self {
+            content: content.into(),
+            cell_type: TableCellType::Change(change_type),
+            sort_key: None,
+            export_value: None,
+        }
+    }
+
+    /// Celda numérica: guarda el valor crudo (orden y exportación) y lo
+    /// muestra con `format`
+    pub fn numeric(value: f64, format: &NumberFormat) -> Self {
+        Self {
+            content: format.format(value),
+            cell_type: TableCellType::Numeric {
+                decimal_align: None,
+                decimal_separator: format.locale.decimal_separator(),
+            },
+            sort_key: Some(SortKey::Number(value)),
+            export_value: Some(value.to_string()),
+        }
+    }
+
+    /// Celda de cambio desde el valor crudo: el color sale del signo del
+    /// valor ya redondeado (un -0.001% que se muestra "0.00%" es neutral)
+    ///
+    /// ```
+    /// use hubermann_ui::{NumberFormat, TableCell, TableCellType, TableChangeType};
+    ///
+    /// let cell = TableCell::delta(-1.234, &NumberFormat::change(2));
+    /// assert_eq!(cell.content, "-1.23%");
+    /// assert!(cell.cell_type == TableCellType::Change(TableChangeType::Bearish));
+    /// ```
+    pub fn delta(value: f64, format: &NumberFormat) -> Self {
+        let change_type = match value {
+            _ if format.rounds_to_zero(value) => TableChangeType::Neutral,
+            v if v > 0.0 => TableChangeType::Bullish,
+            _ => TableChangeType::Bearish,
+        };
+        Self {
+            content: format.format(value),
+            cell_type: TableCellType::Change(change_type),
+            sort_key: Some(SortKey::Number(value)),
+            export_value: Some(value.to_string()),
+        }
+    }
+
+    /// Celda con un `Sparkline` compacto (ej: tendencia de 7 días)
+    pub fn sparkline(data: Vec<f64>) -> Self {
+        Self::custom(html! { <Sparkline data={data} width={96.0} height={24.0} /> })
+    }
+
+    pub fn custom(html: Html) -> Self {
+        Self {
+            content: String::new(),
+            cell_type: TableCellType::Custom(html),
+            sort_key: None,
+            export_value: None,
+        }
+    }
+
+    /// Fija la clave de orden de la celda (ej: un timestamp detrás de "2h ago")
+    pub fn with_sort_key(mut self, sort_key: SortKey) -> Self {
+        self.sort_key = Some(sort_key);
+        self
+    }
+
+    /// Alinea una celda numérica en el punto decimal reservando `width`
+    /// caracteres para la parte decimal (incluye el punto y el sufijo)
+    pub fn with_decimal_align(mut self, width: usize) -> Self {
+        if let TableCellType::Numeric { decimal_align, .. } = &mut self.cell_type {
+            *decimal_align = Some(width);
+        }
+        self
+    }
+
+    /// Parte entera y parte decimal (desde el separador) de una celda
+    /// numérica, para alinear en el separador decimal
+    ///
+    /// ```
+    /// use hubermann_ui::{Locale, NumberFormat, TableCell};
+    ///
+    /// let en = TableCell::numeric(1234.56, &NumberFormat::currency("$", 2));
+    /// assert_eq!(en.decimal_parts(), ("$1,234", ".56"));
+    ///
+    /// let ar = TableCell::numeric(1234.56, &NumberFormat::percent(2).with_locale(Locale::EsAr));
+    /// assert_eq!(ar.decimal_parts(), ("1.234", ",56 %"));
+    ///
+    /// let whole = TableCell::numeric(1234.0, &NumberFormat::new(0).with_locale(Locale::EsAr));
+    /// assert_eq!(whole.decimal_parts(), ("1.234", ""));
+    /// ```
+    pub fn decimal_parts(&self) -> (&str, &str) {
+        let separator = match self.cell_type {
+            TableCellType::Numeric { decimal_separator, .. } => decimal_separator,
+            _ => '.',
+        };
+        match self.content.find(separator) {
+            Some(point) => self.content.split_at(point),
+            None => (self.content.as_str(), ""),
+        }
+    }
+
+    /// Fija el valor exportado (necesario para exportar celdas Custom)
+    pub fn with_export_value(mut self, value: impl Into<String>) -> Self {
+        self.export_value = Some(value.into());
+        self
+    }
+
+    /// Clave de orden efectiva: la explícita o la derivada del content.
+    /// Las celdas Custom sin clave explícita quedan siempre al final.
+    pub fn sort_key(&self) -> SortKey {
+        match (&self.sort_key, &self.cell_type) {
+            (Some(key), _) => key.clone(),
+            (None, TableCellType::Custom(_)) => SortKey::Empty,
+            (None, TableCellType::Numeric { decimal_separator: ',', .. }) => {
+                SortKey::parse_locale(&self.content, Locale::EsAr)
+            }
+            (None, _) => SortKey::parse(&self.content),
+        }
+    }
+}
+
+/// Helper para renderizar una celda según su tipo
+pub(crate) fn render_cell(cell: &TableCell) -> Html {
+    match &cell.cell_type {
+        TableCellType::Text => {
+            html! {
+                <span class="text-text-secondary">{&cell.content}</span>
+            }
+        }
+        TableCellType::Primary => {
+            html! {
+                <span class="text-text-primary font-medium">{&cell.content}</span>
+            }
+        }
+        TableCellType::Secondary => {
+            html! {
+                <span class="text-text-tertiary">{&cell.content}</span>
+            }
+        }
+        TableCellType::Change(change_type) => {
+            let color_class = match change_type {
+                TableChangeType::Bullish => "text-bullish",
+                TableChangeType::Bearish => "text-bearish",
+                TableChangeType::Neutral => "text-neutral",
+            };
+
+            html! {
+                <span class={color_class}>{&cell.content}</span>
+            }
+        }
+        TableCellType::Numeric { decimal_align, .. } => {
+            let content = match decimal_align {
+                Some(width) => {
+                    let (integer, fraction) = cell.decimal_parts();
+                    html! {
+                        <>
+                            {integer}
+                            <span class="inline-block text-left" style={format!("min-width: {}ch", width)}>
+                                {fraction}
+                            </span>
+                        </>
+                    }
+                }
+                None => html! { {&cell.content} },
+            };
+
+            html! {
+                <span class="block text-right font-mono tabular-nums text-text-secondary">{content}</span>
+            }
+        }
+        TableCellType::Custom(html) => html.clone(),
+    }
+}
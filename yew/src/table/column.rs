@@ -0,0 +1,376 @@
+use std::rc::Rc;
+
+use yew::Html;
+
+use super::cell::{render_cell, TableCell, TableCellType, TableRow};
+use super::edit::{CellEditor, Validator};
+use super::filter::FilterKind;
+use super::resize::DEFAULT_MIN_WIDTH;
+use super::sort::SortKey;
+
+/// Definición tipada de una columna
+///
+/// Describe cómo se ve y se comporta una columna a partir de la struct de
+/// dominio `T` de cada fila: el renderer devuelve un `TableCell`, y los
+/// accessors opcionales de sort/filter permiten ordenar o filtrar por el
+/// valor crudo en vez del texto formateado.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use hubermann_ui::*;
+///
+/// #[derive(PartialEq)]
+/// struct Position { symbol: String, qty: f64, pnl_pct: f64 }
+///
+/// let columns = vec![
+///     TableColumn::new("symbol", "Symbol", |p: &Position| TableCell::primary(&p.symbol)),
+///     TableColumn::new("qty", "Qty", |p: &Position| TableCell::text(format!("{}", p.qty)))
+///         .with_align(TableAlign::Right)
+///         .with_width("96px"),
+///     TableColumn::new("pnl", "P&L", |p: &Position| {
+///         let change = if p.pnl_pct >= 0.0 { TableChangeType::Bullish } else { TableChangeType::Bearish };
+///         TableCell::change(format!("{:+.2}%", p.pnl_pct), change)
+///     })
+///     .with_sort_by(|p: &Position| SortKey::Number(p.pnl_pct)),
+/// ];
+/// ```
+pub struct TableColumn<T> {
+    /// Identificador estable (usado por sort y callbacks)
+    pub id: String,
+    /// Texto del header
+    pub header: String,
+    /// Alineación del header y las celdas
+    pub align: TableAlign,
+    /// Ancho CSS opcional (ej: "120px", "20%")
+    pub width: Option<String>,
+    /// Ancho mínimo al redimensionar (px, default: 48)
+    pub min_width: f64,
+    /// Ancho máximo al redimensionar (px)
+    pub max_width: Option<f64>,
+    /// Si el header es clickeable cuando la tabla es `sortable`
+    pub sortable: bool,
+    /// Filtro que ofrece en la fila de filtros (None = sin filtro)
+    pub filter: Option<FilterKind>,
+    /// Editor inline (None = no editable)
+    pub editor: Option<CellEditor>,
+    render: Accessor<T, TableCell>,
+    render_html: Option<Accessor<T, Html>>,
+    sort_by: Option<Accessor<T, SortKey>>,
+    filter_by: Option<Accessor<T, String>>,
+    export_by: Option<Accessor<T, String>>,
+    edit_value: Option<Accessor<T, String>>,
+    validator: Option<Validator>,
+}
+
+/// Closure que extrae un valor de una fila
+pub(crate) type Accessor<T, R> = Rc<dyn Fn(&T) -> R>;
+
+/// Alineación horizontal de una columna
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TableAlign {
+    Left,
+    Center,
+    Right,
+}
+
+impl TableAlign {
+    pub(crate) fn class(&self) -> &'static str {
+        match self {
+            TableAlign::Left => "text-left",
+            TableAlign::Center => "text-center",
+            TableAlign::Right => "text-right",
+        }
+    }
+}
+
+impl<T> TableColumn<T> {
+    pub fn new(
+        id: impl Into<String>,
+        header: impl Into<String>,
+        render: impl Fn(&T) -> TableCell + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            header: header.into(),
+            align: TableAlign::Left,
+            width: None,
+            min_width: DEFAULT_MIN_WIDTH,
+            max_width: None,
+            sortable: true,
+            filter: None,
+            editor: None,
+            render: Rc::new(render),
+            render_html: None,
+            sort_by: None,
+            filter_by: None,
+            export_by: None,
+            edit_value: None,
+            validator: None,
+        }
+    }
+
+    /// Columna custom: cada fila se renderiza con `render` a partir de su
+    /// dato (Badge, Button, Tooltip, ...). Como las celdas Custom, ordena y
+    /// exporta solo con `with_sort_by` / `with_export_by`.
+    ///
+    /// # Ejemplo
+    /// ```rust,ignore
+    /// TableColumn::custom("status", "Status", |o: &Order| html! {
+    ///     <Badge variant={o.status.badge_variant()}>{o.status.label()}</Badge>
+    /// })
+    /// .with_sort_by(|o: &Order| SortKey::Text(o.status.label().to_string()))
+    /// ```
+    pub fn custom(
+        id: impl Into<String>,
+        header: impl Into<String>,
+        render: impl Fn(&T) -> Html + 'static,
+    ) -> Self {
+        let empty = TableCell::custom(Html::default());
+        Self {
+            render_html: Some(Rc::new(render)),
+            ..Self::new(id, header, move |_| empty.clone())
+        }
+    }
+
+    pub fn with_align(mut self, align: TableAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_width(mut self, width: impl Into<String>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Límite inferior al redimensionar (px)
+    pub fn with_min_width(mut self, min_width: f64) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Límite superior al redimensionar (px)
+    pub fn with_max_width(mut self, max_width: f64) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Accessor de orden (default: la `sort_key` de la celda renderizada)
+    pub fn with_sort_by(mut self, sort_by: impl Fn(&T) -> SortKey + 'static) -> Self {
+        self.sort_by = Some(Rc::new(sort_by));
+        self
+    }
+
+    /// Agrega un filtro de columna (texto, rango numérico u opciones)
+    pub fn with_filter(mut self, filter: FilterKind) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Accessor de filtro (default: el `content` de la celda renderizada)
+    pub fn with_filter_by(mut self, filter_by: impl Fn(&T) -> String + 'static) -> Self {
+        self.filter_by = Some(Rc::new(filter_by));
+        self
+    }
+
+    /// Accessor de exportación CSV/TSV (default: el export value o el content de la celda)
+    pub fn with_export_by(mut self, export_by: impl Fn(&T) -> String + 'static) -> Self {
+        self.export_by = Some(Rc::new(export_by));
+        self
+    }
+
+    /// Hace la columna editable inline (ver `oncelledit` en `Table`)
+    pub fn with_editor(mut self, editor: CellEditor) -> Self {
+        self.editor = Some(editor);
+        self
+    }
+
+    /// Valor inicial del editor (default: el valor de exportación)
+    pub fn with_edit_value(mut self, edit_value: impl Fn(&T) -> String + 'static) -> Self {
+        self.edit_value = Some(Rc::new(edit_value));
+        self
+    }
+
+    /// Valida el valor editado; el Err se muestra como error del editor
+    pub fn with_validator(mut self, validator: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        self.validator = Some(Rc::new(validator));
+        self
+    }
+
+    /// Celda a mostrar para una fila
+    pub fn render(&self, row: &T) -> TableCell {
+        (self.render)(row)
+    }
+
+    /// Contenido de la celda de una fila: el renderer de `custom` o la
+    /// celda ya renderizada
+    pub(crate) fn render_content(&self, row: &T, cell: &TableCell) -> Html {
+        match &self.render_html {
+            Some(render_html) => render_html(row),
+            None => render_cell(cell),
+        }
+    }
+
+    /// Clave de orden de una fila en esta columna
+    pub fn sort_key(&self, row: &T) -> SortKey {
+        match &self.sort_by {
+            Some(sort_by) => sort_by(row),
+            None => self.render(row).sort_key(),
+        }
+    }
+
+    /// Clave de orden reutilizando la celda ya renderizada de la fila
+    pub(crate) fn cell_sort_key(&self, row: &T, cell: &TableCell) -> SortKey {
+        match &self.sort_by {
+            Some(sort_by) => sort_by(row),
+            None => cell.sort_key(),
+        }
+    }
+
+    /// Valor textual de una fila para filtrar en esta columna
+    pub fn filter_value(&self, row: &T) -> String {
+        match &self.filter_by {
+            Some(filter_by) => filter_by(row),
+            None => self.render(row).content,
+        }
+    }
+
+    /// Valor con el que arranca el editor inline de una fila
+    pub fn edit_value(&self, row: &T) -> String {
+        match &self.edit_value {
+            Some(edit_value) => edit_value(row),
+            None => self.export_value(row),
+        }
+    }
+
+    /// Valida un valor editado: primero el tipo del editor y después el
+    /// validator de la columna
+    ///
+    /// ```
+    /// use hubermann_ui::{CellEditor, TableCell, TableColumn};
+    ///
+    /// let column = TableColumn::new("stop", "Stop", |stop: &f64| TableCell::text(stop.to_string()))
+    ///     .with_editor(CellEditor::Number)
+    ///     .with_validator(|value| match value.parse::<f64>() {
+    ///         Ok(v) if v > 0.0 => Ok(()),
+    ///         _ => Err("Must be positive".to_string()),
+    ///     });
+    ///
+    /// assert_eq!(column.validate("98.5"), Ok(()));
+    /// assert_eq!(column.validate("abc"), Err("Enter a number".to_string()));
+    /// assert_eq!(column.validate("-1"), Err("Must be positive".to_string()));
+    /// ```
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        if self.editor == Some(CellEditor::Number) && value.trim().parse::<f64>().is_err() {
+            return Err("Enter a number".to_string());
+        }
+        match &self.validator {
+            Some(validator) => validator(value),
+            None => Ok(()),
+        }
+    }
+
+    /// Valor de una fila para exportar a CSV/TSV
+    ///
+    /// En orden: `with_export_by`, el `export_value` de la celda (las
+    /// celdas `numeric` y `delta` guardan el valor crudo) o su content tal
+    /// cual ("00123" sigue siendo "00123"). Las celdas Custom sin export
+    /// value se exportan vacías.
+    pub fn export_value(&self, row: &T) -> String {
+        if let Some(export_by) = &self.export_by {
+            return export_by(row);
+        }
+
+        let cell = self.render(row);
+        match (cell.export_value, &cell.cell_type) {
+            (Some(value), _) => value,
+            (None, TableCellType::Custom(_)) => String::new(),
+            (None, _) => cell.content,
+        }
+    }
+}
+
+impl TableColumn<TableRow> {
+    /// Columnas posicionales para filas armadas con `TableRow`
+    ///
+    /// El id de cada columna es su índice ("0", "1", ...). Si una fila tiene
+    /// menos celdas que headers, la celda faltante se muestra vacía.
+    ///
+    /// ```rust,ignore
+    /// let headers = vec!["Symbol".to_string(), "Price".to_string()];
+    /// html! {
+    ///     <Table<TableRow> columns={TableColumn::from_headers(headers)} rows={rows} />
+    /// }
+    /// ```
+    pub fn from_headers(headers: Vec<String>) -> Vec<Self> {
+        headers
+            .into_iter()
+            .enumerate()
+            .map(|(idx, header)| {
+                TableColumn::new(idx.to_string(), header, move |row: &TableRow| {
+                    row.cells
+                        .get(idx)
+                        .cloned()
+                        .unwrap_or_else(|| TableCell::text(""))
+                })
+            })
+            .collect()
+    }
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            header: self.header.clone(),
+            align: self.align,
+            width: self.width.clone(),
+            min_width: self.min_width,
+            max_width: self.max_width,
+            sortable: self.sortable,
+            filter: self.filter.clone(),
+            editor: self.editor.clone(),
+            render: self.render.clone(),
+            render_html: self.render_html.clone(),
+            sort_by: self.sort_by.clone(),
+            filter_by: self.filter_by.clone(),
+            export_by: self.export_by.clone(),
+            edit_value: self.edit_value.clone(),
+            validator: self.validator.clone(),
+        }
+    }
+}
+
+// Los closures se comparan por identidad (Rc::ptr_eq), igual que Callback
+impl<T> PartialEq for TableColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.header == other.header
+            && self.align == other.align
+            && self.width == other.width
+            && self.min_width == other.min_width
+            && self.max_width == other.max_width
+            && self.sortable == other.sortable
+            && self.filter == other.filter
+            && self.editor == other.editor
+            && Rc::ptr_eq(&self.render, &other.render)
+            && opt_ptr_eq(&self.render_html, &other.render_html)
+            && opt_ptr_eq(&self.sort_by, &other.sort_by)
+            && opt_ptr_eq(&self.filter_by, &other.filter_by)
+            && opt_ptr_eq(&self.export_by, &other.export_by)
+            && opt_ptr_eq(&self.edit_value, &other.edit_value)
+            && opt_ptr_eq(&self.validator, &other.validator)
+    }
+}
+
+fn opt_ptr_eq<F: ?Sized>(a: &Option<Rc<F>>, b: &Option<Rc<F>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,220 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::body::BodyRow;
+use super::column::Accessor;
+use super::group::DisplayRow;
+use super::navigation::{GridCell, GridNavigation};
+use crate::accordion::accordion_chevron;
+
+/// Contenido expandible debajo de cada fila
+///
+/// Renderiza un `Html` arbitrario (fills, comisiones, notas) que ocupa
+/// todas las columnas. La tabla agrega una columna con chevron para
+/// expandir/colapsar cada fila.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// html! {
+///     <Table<Trade>
+///         columns={columns}
+///         rows={trades}
+///         row_key={Some(TableRowKey::new(|t: &Trade| t.id.clone()))}
+///         row_detail={Some(TableRowDetail::new(|t: &Trade| html! {
+///             <TradeFills fills={t.fills.clone()} />
+///         }))}
+///     />
+/// }
+/// ```
+pub struct TableRowDetail<T>(Accessor<T, Html>);
+
+impl<T> TableRowDetail<T> {
+    pub fn new(render: impl Fn(&T) -> Html + 'static) -> Self {
+        Self(Rc::new(render))
+    }
+
+    pub fn render(&self, row: &T) -> Html {
+        (self.0)(row)
+    }
+}
+
+impl<T> Clone for TableRowDetail<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for TableRowDetail<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Expande o colapsa una fila
+///
+/// ```
+/// use hubermann_ui::toggle_expanded;
+///
+/// let expanded = toggle_expanded(&[], "T-1", None);
+/// assert_eq!(expanded, vec!["T-1"]);
+/// // Toggle
+/// assert!(toggle_expanded(&expanded, "T-1", None).is_empty());
+/// // Forzar estado (ej: ArrowRight / ArrowLeft)
+/// assert_eq!(toggle_expanded(&expanded, "T-1", Some(true)), vec!["T-1"]);
+/// ```
+pub fn toggle_expanded(expanded: &[String], key: &str, open: Option<bool>) -> Vec<String> {
+    let is_open = expanded.iter().any(|k| k == key);
+    match open.unwrap_or(!is_open) {
+        true if !is_open => {
+            let mut next = expanded.to_vec();
+            next.push(key.to_string());
+            next
+        }
+        false => expanded.iter().filter(|k| *k != key).cloned().collect(),
+        _ => expanded.to_vec(),
+    }
+}
+
+/// Filas expandidas de un render
+#[derive(Clone)]
+pub(crate) struct RowExpansion {
+    /// Si hay `row_detail`
+    pub enabled: bool,
+    expanded: Rc<Vec<String>>,
+    set_expanded: Callback<Vec<String>>,
+}
+
+/// Filas expandidas: controladas si el parent escucha los cambios
+#[hook]
+pub(crate) fn use_row_expansion(
+    enabled: bool,
+    expanded: Option<Vec<String>>,
+    onexpandchange: Option<Callback<Vec<String>>>,
+) -> RowExpansion {
+    let internal_expanded = use_state(Vec::<String>::new);
+    let expanded = Rc::new(match onexpandchange {
+        Some(_) => expanded.unwrap_or_default(),
+        None => (*internal_expanded).clone(),
+    });
+
+    let set_expanded = Callback::from(move |expanded: Vec<String>| match &onexpandchange {
+        Some(callback) => callback.emit(expanded),
+        None => internal_expanded.set(expanded),
+    });
+
+    RowExpansion {
+        enabled,
+        expanded,
+        set_expanded,
+    }
+}
+
+impl RowExpansion {
+    pub fn is_expanded(&self, key: &str) -> bool {
+        self.enabled && self.expanded.iter().any(|expanded| expanded == key)
+    }
+
+    /// Expande o colapsa la fila `key` (ver `toggle_expanded`)
+    pub fn toggle(&self, key: &str, open: Option<bool>) -> impl Fn() + 'static {
+        let expanded = self.expanded.clone();
+        let set_expanded = self.set_expanded.clone();
+        let key = key.to_string();
+        move || set_expanded.emit(toggle_expanded(&expanded, &key, open))
+    }
+}
+
+/// Agrega el detalle debajo de cada fila expandida
+///
+/// `expanded` recibe la posición de la fila en el orden visible.
+pub(crate) fn with_detail_rows(display: Vec<DisplayRow>, expanded: impl Fn(usize) -> bool) -> Vec<DisplayRow> {
+    display
+        .into_iter()
+        .flat_map(|entry| match entry {
+            DisplayRow::Data(position) if expanded(position) => {
+                vec![DisplayRow::Data(position), DisplayRow::Detail(position)]
+            }
+            entry => vec![entry],
+        })
+        .collect()
+}
+
+/// Celda con el chevron que expande/colapsa una fila
+pub(crate) fn expand_cell(
+    expansion: &RowExpansion,
+    key: &str,
+    grid: GridCell,
+    (frozen_class, frozen_style): (Classes, Option<String>),
+) -> Html {
+    let is_expanded = expansion.is_expanded(key);
+    let onexpand = {
+        let toggle = expansion.toggle(key, None);
+        Callback::from(move |_: MouseEvent| toggle())
+    };
+    let onexpandkey = {
+        let (expand, collapse) = (expansion.toggle(key, Some(true)), expansion.toggle(key, Some(false)));
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "ArrowRight" => {
+                e.prevent_default();
+                expand();
+            }
+            "ArrowLeft" => {
+                e.prevent_default();
+                collapse();
+            }
+            _ => {}
+        })
+    };
+
+    html! {
+        <td
+            role={grid.role}
+            tabindex={grid.tabindex}
+            aria-colindex={grid.colindex}
+            onfocusin={grid.onfocusin}
+            style={frozen_style}
+            class={classes!("w-10", "px-3", "py-3", frozen_class)}
+        >
+            <button
+                type="button"
+                tabindex={grid.inner_tabindex}
+                aria-label={if is_expanded { "Collapse row" } else { "Expand row" }}
+                aria-expanded={is_expanded.to_string()}
+                aria-controls={is_expanded.then(|| format!("detail-{}", key))}
+                onclick={onexpand}
+                onkeydown={onexpandkey}
+                class="flex items-center rounded hover:bg-bg-tertiary transition-colors"
+            >
+                {accordion_chevron(is_expanded, "w-4 h-4")}
+            </button>
+        </td>
+    }
+}
+
+/// Fila con el detalle expandido de la fila `key`, ocupando todas las columnas
+pub(crate) fn detail_row(row: BodyRow, key: &str, content: Html, column_count: usize, grid: &GridNavigation) -> Html {
+    let cell = grid.cell(row.index, 0);
+
+    html! {
+        <tr
+            key={format!("detail:{}", key)}
+            ref={row.node_ref}
+            aria-rowindex={grid.row_index(row.index)}
+            onkeydown={grid.keydown(row.index, None, None)}
+            class={classes!("bg-bg-primary/40", row.border_class)}
+        >
+            <td
+                id={format!("detail-{}", key)}
+                colspan={column_count.to_string()}
+                role={cell.role}
+                tabindex={cell.tabindex}
+                aria-colindex={cell.colindex}
+                aria-colspan={grid.enabled.then(|| column_count.to_string())}
+                onfocusin={cell.onfocusin}
+                class="px-3 py-3"
+            >
+                {content}
+            </td>
+        </tr>
+    }
+}
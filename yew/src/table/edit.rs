@@ -0,0 +1,211 @@
+use std::rc::Rc;
+
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, KeyboardEvent};
+use yew::prelude::*;
+
+use super::column::TableColumn;
+use crate::input::Input;
+use crate::select::{Select, SelectOption};
+
+/// Editor inline de una columna editable
+///
+/// Doble click o Enter sobre la celda la convierte en el editor; Enter o
+/// Tab confirman, Escape cancela.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// TableColumn::new("target", "Target %", |a: &Allocation| TableCell::text(format!("{:.1}%", a.target)))
+///     .with_editor(CellEditor::Number)
+///     .with_edit_value(|a: &Allocation| a.target.to_string())
+///     .with_validator(|value| match value.parse::<f64>() {
+///         Ok(v) if (0.0..=100.0).contains(&v) => Ok(()),
+///         _ => Err("Between 0 and 100".to_string()),
+///     });
+/// ```
+#[derive(Clone, PartialEq)]
+pub enum CellEditor {
+    /// `Input` de texto
+    Text,
+    /// `Input` numérico; rechaza lo que no sea un número
+    Number,
+    /// `Select` entre opciones fijas (confirma al elegir)
+    Options(Vec<SelectOption>),
+}
+
+/// Edición confirmada de una celda: fila (por key), columna (por id) y
+/// el valor nuevo tal cual lo escribió el usuario
+#[derive(Clone, PartialEq, Debug)]
+pub struct CellEdit {
+    pub row_key: String,
+    pub column_id: String,
+    pub value: String,
+}
+
+/// Validator de una columna editable
+pub(crate) type Validator = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+/// Celda en edición
+#[derive(Clone, PartialEq)]
+pub(crate) struct EditingCell {
+    pub row_key: String,
+    pub column_id: String,
+    pub value: String,
+    pub error: Option<String>,
+}
+
+/// Estado de la celda en edición más el ref del editor, que recibe el foco
+/// al abrirse
+#[hook]
+pub(crate) fn use_cell_editing() -> (UseStateHandle<Option<EditingCell>>, NodeRef) {
+    let editing = use_state(|| None::<EditingCell>);
+    let editor_ref = use_node_ref();
+
+    {
+        let editor_ref = editor_ref.clone();
+        let target = editing
+            .as_ref()
+            .map(|cell| (cell.row_key.clone(), cell.column_id.clone()));
+
+        use_effect_with(target, move |_| {
+            let field = editor_ref
+                .cast::<HtmlElement>()
+                .and_then(|editor| editor.query_selector("input, select").ok().flatten())
+                .and_then(|field| field.dyn_into::<HtmlElement>().ok());
+            if let Some(field) = field {
+                let _ = field.focus();
+            }
+        });
+    }
+
+    (editing, editor_ref)
+}
+
+/// Doble click y Enter que abren el editor en `start`
+pub(crate) fn edit_triggers(
+    editing: &UseStateHandle<Option<EditingCell>>,
+    start: EditingCell,
+) -> (Callback<MouseEvent>, Callback<KeyboardEvent>) {
+    let ondblclick = {
+        let editing = editing.clone();
+        let start = start.clone();
+        Callback::from(move |_: MouseEvent| editing.set(Some(start.clone())))
+    };
+    let onkeydown = {
+        let editing = editing.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() == "Enter" {
+                e.prevent_default();
+                editing.set(Some(start.clone()));
+            }
+        })
+    };
+    (ondblclick, onkeydown)
+}
+
+/// Editor de la celda en edición
+pub(crate) fn cell_editor<T: 'static>(
+    column: &TableColumn<T>,
+    cell: &EditingCell,
+    editing: &UseStateHandle<Option<EditingCell>>,
+    oncelledit: &Option<Callback<CellEdit>>,
+    editor_ref: &NodeRef,
+) -> Html {
+    let Some(editor) = &column.editor else {
+        return html! {};
+    };
+
+    // Confirma si valida; si no, deja el editor abierto con el error
+    let commit = {
+        let column = column.clone();
+        let cell = cell.clone();
+        let editing = editing.clone();
+        let oncelledit = oncelledit.clone();
+        Rc::new(move |value: String| match column.validate(&value) {
+            Ok(()) => {
+                if let Some(callback) = &oncelledit {
+                    callback.emit(CellEdit {
+                        row_key: cell.row_key.clone(),
+                        column_id: cell.column_id.clone(),
+                        value,
+                    });
+                }
+                editing.set(None);
+                true
+            }
+            Err(error) => {
+                editing.set(Some(EditingCell {
+                    value,
+                    error: Some(error),
+                    ..cell.clone()
+                }));
+                false
+            }
+        })
+    };
+
+    let onkeydown = {
+        let commit = commit.clone();
+        let editing = editing.clone();
+        let value = cell.value.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            // Las flechas y demás teclas quedan dentro del editor
+            e.stop_propagation();
+            // Tab inválido no mueve el foco; Tab válido sigue a la próxima celda
+            let handled = match e.key().as_str() {
+                "Enter" => {
+                    commit(value.clone());
+                    true
+                }
+                "Tab" => !commit(value.clone()),
+                "Escape" => {
+                    editing.set(None);
+                    true
+                }
+                _ => false,
+            };
+            if handled {
+                e.prevent_default();
+            }
+        })
+    };
+
+    let oninput = {
+        let editing = editing.clone();
+        let cell = cell.clone();
+        Callback::from(move |value: String| {
+            editing.set(Some(EditingCell {
+                value,
+                error: None,
+                ..cell.clone()
+            }));
+        })
+    };
+
+    let field = match editor {
+        CellEditor::Text | CellEditor::Number => html! {
+            <Input
+                input_type={if *editor == CellEditor::Number { "number" } else { "text" }}
+                value={cell.value.clone()}
+                error={cell.error.clone()}
+                oninput={oninput}
+            />
+        },
+        CellEditor::Options(options) => html! {
+            <Select
+                options={options.clone()}
+                value={cell.value.clone()}
+                error={cell.error.clone()}
+                onchange={Callback::from(move |value: String| {
+                    commit(value);
+                })}
+            />
+        },
+    };
+
+    html! {
+        <div ref={editor_ref.clone()} onkeydown={onkeydown} class="min-w-[6rem]">
+            {field}
+        </div>
+    }
+}
@@ -0,0 +1,203 @@
+use gloo::file::{Blob, ObjectUrl};
+use gloo::timers::callback::Timeout;
+use gloo::utils::{document, window};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlAnchorElement;
+use yew::prelude::*;
+
+use super::column::TableColumn;
+use crate::dropdown::{Dropdown, DropdownItem, DropdownPosition};
+
+/// Formato de exportación de la tabla
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ExportFormat {
+    /// Separado por comas (RFC 4180)
+    Csv,
+    /// Separado por tabs (pegar en una planilla)
+    Tsv,
+}
+
+impl ExportFormat {
+    pub fn delimiter(&self) -> char {
+        match self {
+            ExportFormat::Csv => ',',
+            ExportFormat::Tsv => '\t',
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Tsv => "tsv",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Tsv => "text/tab-separated-values",
+        }
+    }
+}
+
+/// Configuración del botón de exportación de la tabla
+///
+/// Agrega un toolbar con un `Dropdown` para descargar CSV/TSV o copiar TSV
+/// al clipboard. Se exportan todas las filas en el orden actual (ordenadas
+/// y filtradas, no solo la página visible).
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// html! {
+///     <Table<Position>
+///         columns={columns}
+///         rows={positions}
+///         export={Some(TableExport::new("positions"))}
+///     />
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct TableExport {
+    /// Nombre del archivo sin extensión
+    pub filename: String,
+}
+
+impl TableExport {
+    pub fn new(filename: impl Into<String>) -> Self {
+        Self {
+            filename: filename.into(),
+        }
+    }
+}
+
+/// Escapa un campo: lo envuelve en comillas si contiene el delimitador,
+/// comillas o saltos de línea, duplicando las comillas internas
+///
+/// ```
+/// use hubermann_ui::escape_field;
+///
+/// assert_eq!(escape_field("AAPL", ','), "AAPL");
+/// assert_eq!(escape_field("Apple, Inc.", ','), "\"Apple, Inc.\"");
+/// assert_eq!(escape_field("5\" screen", ','), "\"5\"\" screen\"");
+/// assert_eq!(escape_field("Apple, Inc.", '\t'), "Apple, Inc.");
+/// ```
+pub fn escape_field(value: &str, delimiter: char) -> String {
+    if value.contains(delimiter) || value.contains(['"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Serializa filas a CSV/TSV con una línea de headers
+///
+/// Las celdas `numeric` y `delta` se exportan con su valor crudo
+/// ("$1,182.45" como 1182.45); el resto con su content tal cual (ver
+/// `TableColumn::export_value`).
+///
+/// ```
+/// use hubermann_ui::{export_rows, ExportFormat, NumberFormat, TableCell, TableColumn, TableRow};
+///
+/// let columns = TableColumn::from_headers(vec!["Symbol".into(), "Price".into()]);
+/// let price = NumberFormat::currency("$", 2);
+/// let rows = vec![
+///     TableRow::new(vec![TableCell::primary("AAPL"), TableCell::numeric(1182.45, &price)]),
+///     TableRow::new(vec![TableCell::primary("Apple, Inc."), TableCell::text("-")]),
+///     TableRow::new(vec![TableCell::primary("00123"), TableCell::text("+2.3%")]),
+/// ];
+///
+/// assert_eq!(
+///     export_rows(&columns, &rows, ExportFormat::Csv),
+///     "Symbol,Price\nAAPL,1182.45\n\"Apple, Inc.\",-\n00123,+2.3%\n"
+/// );
+/// ```
+pub fn export_rows<'a, T: 'a>(
+    columns: &[TableColumn<T>],
+    rows: impl IntoIterator<Item = &'a T>,
+    format: ExportFormat,
+) -> String {
+    let delimiter = format.delimiter();
+    let join = |fields: Vec<String>| {
+        fields
+            .iter()
+            .map(|field| escape_field(field, delimiter))
+            .collect::<Vec<_>>()
+            .join(&delimiter.to_string())
+    };
+
+    let mut output = join(columns.iter().map(|column| column.header.clone()).collect());
+    output.push('\n');
+    for row in rows {
+        output.push_str(&join(columns.iter().map(|column| column.export_value(row)).collect()));
+        output.push('\n');
+    }
+    output
+}
+
+/// Descarga `content` como archivo desde el browser
+pub(crate) fn download(filename: &str, content: &str, format: ExportFormat) {
+    let blob = Blob::new_with_options(content, Some(format.mime_type()));
+    let url = ObjectUrl::from(blob);
+
+    if let Ok(anchor) = document().create_element("a") {
+        let anchor: HtmlAnchorElement = anchor.unchecked_into();
+        anchor.set_href(&url);
+        anchor.set_download(&format!("{}.{}", filename, format.extension()));
+        anchor.click();
+    }
+
+    // Revocar la URL recién después de que arranque la descarga
+    Timeout::new(0, move || drop(url)).forget();
+}
+
+/// Copia `content` al clipboard (fire-and-forget)
+pub(crate) fn copy_to_clipboard(content: &str) {
+    let _ = window().navigator().clipboard().write_text(content);
+}
+
+/// Exportación desde el toolbar
+///
+/// El click pide el formato (y si se copia en vez de descargar) y el
+/// contenido se arma en el próximo render con `rows` en el orden de
+/// `order`: todas las filas filtradas, no solo la página. Devuelve el
+/// callback que pide una exportación.
+#[hook]
+pub(crate) fn use_table_export<T>(
+    filename: String,
+    columns: &[TableColumn<T>],
+    rows: &[T],
+    order: &[usize],
+) -> Callback<(ExportFormat, bool)> {
+    let pending_export = use_state_eq(|| None::<(ExportFormat, bool)>);
+
+    {
+        let content = pending_export.map(|(format, _)| export_rows(columns, order.iter().map(|&idx| &rows[idx]), format));
+        let pending_export = pending_export.clone();
+
+        use_effect_with(*pending_export, move |pending| {
+            if let (Some((format, copy)), Some(content)) = (*pending, content) {
+                if copy {
+                    copy_to_clipboard(&content);
+                } else {
+                    download(&filename, &content, format);
+                }
+                pending_export.set(None);
+            }
+        });
+    }
+
+    Callback::from(move |request| pending_export.set(Some(request)))
+}
+
+/// Menú "Export" del toolbar
+pub(crate) fn export_menu(request: &Callback<(ExportFormat, bool)>) -> Html {
+    let action = |format: ExportFormat, copy: bool| request.reform(move |_: MouseEvent| (format, copy));
+
+    html! {
+        <Dropdown trigger={html! { <span>{"Export"}</span> }} position={DropdownPosition::Right}>
+            <DropdownItem onclick={action(ExportFormat::Csv, false)}>{"Download CSV"}</DropdownItem>
+            <DropdownItem onclick={action(ExportFormat::Tsv, false)}>{"Download TSV"}</DropdownItem>
+            <DropdownItem onclick={action(ExportFormat::Tsv, true)}>{"Copy to clipboard"}</DropdownItem>
+        </Dropdown>
+    }
+}
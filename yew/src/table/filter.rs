@@ -0,0 +1,466 @@
+use yew::prelude::*;
+
+use super::column::TableColumn;
+use super::resize::FrozenColumns;
+use super::sort::SortKey;
+use crate::dropdown::{Dropdown, DropdownItem};
+use crate::input::Input;
+
+/// Tipo de filtro que ofrece una columna en la fila de filtros
+#[derive(Clone, PartialEq, Debug)]
+pub enum FilterKind {
+    /// Texto que contiene (sin distinguir mayúsculas)
+    Text,
+    /// Rango numérico min/max sobre la clave de orden de la celda
+    Range,
+    /// Multi-select entre valores fijos (ej: Bullish/Bearish)
+    Options(Vec<String>),
+}
+
+/// Filtro activo de una columna
+#[derive(Clone, PartialEq, Debug)]
+pub enum ColumnFilter {
+    /// El valor de filtro contiene el texto
+    Contains(String),
+    /// La clave numérica está dentro del rango (bordes incluidos)
+    Range { min: Option<f64>, max: Option<f64> },
+    /// El valor de filtro es uno de los seleccionados
+    OneOf(Vec<String>),
+}
+
+impl ColumnFilter {
+    /// Un filtro vacío no filtra nada (y no se guarda)
+    pub fn is_empty(&self) -> bool {
+        match self {
+            ColumnFilter::Contains(text) => text.trim().is_empty(),
+            ColumnFilter::Range { min, max } => min.is_none() && max.is_none(),
+            ColumnFilter::OneOf(values) => values.is_empty(),
+        }
+    }
+
+    /// Si una fila pasa el filtro en `column`
+    pub fn matches<T>(&self, column: &TableColumn<T>, row: &T) -> bool {
+        match self {
+            ColumnFilter::Contains(text) => contains(&column.filter_value(row), text),
+            ColumnFilter::Range { min, max } => match column.sort_key(row) {
+                SortKey::Number(value) => {
+                    min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+                }
+                _ => false,
+            },
+            ColumnFilter::OneOf(values) => values.contains(&column.filter_value(row)),
+        }
+    }
+}
+
+/// Estado de filtros de la tabla: búsqueda global + filtros por columna
+///
+/// Se serializa como query string para guardarlo en la URL.
+///
+/// ```
+/// use hubermann_ui::{ColumnFilter, TableFilters};
+///
+/// let filters = TableFilters::default()
+///     .with_search("tech")
+///     .with_filter("sector", ColumnFilter::OneOf(vec!["Energy".into(), "Tech, Media".into()]))
+///     .with_filter("price", ColumnFilter::Range { min: Some(10.0), max: None });
+///
+/// let query = filters.to_query();
+/// assert_eq!(query, "q=tech&f.sector=in:Energy,Tech%2C%20Media&f.price=range:10..");
+/// assert_eq!(TableFilters::from_query(&query), filters);
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TableFilters {
+    /// Búsqueda global sobre todas las columnas
+    pub search: String,
+    /// Filtros por id de columna
+    pub columns: Vec<(String, ColumnFilter)>,
+}
+
+impl TableFilters {
+    pub fn with_search(mut self, search: impl Into<String>) -> Self {
+        self.search = search.into();
+        self
+    }
+
+    pub fn with_filter(mut self, column: impl Into<String>, filter: ColumnFilter) -> Self {
+        self.set(&column.into(), Some(filter));
+        self
+    }
+
+    /// Filtro de una columna
+    pub fn get(&self, column: &str) -> Option<&ColumnFilter> {
+        self.columns.iter().find(|(id, _)| id == column).map(|(_, filter)| filter)
+    }
+
+    /// Reemplaza (o quita, con None o un filtro vacío) el filtro de una columna
+    pub fn set(&mut self, column: &str, filter: Option<ColumnFilter>) {
+        match filter.filter(|filter| !filter.is_empty()) {
+            Some(filter) => match self.columns.iter_mut().find(|(id, _)| id == column) {
+                Some((_, current)) => *current = filter,
+                None => self.columns.push((column.to_string(), filter)),
+            },
+            None => self.columns.retain(|(id, _)| id != column),
+        }
+    }
+
+    /// Si hay búsqueda o algún filtro de columna
+    pub fn is_active(&self) -> bool {
+        !self.search.trim().is_empty() || !self.columns.is_empty()
+    }
+
+    /// Si una fila pasa la búsqueda global y todos los filtros de columna
+    pub fn matches<T>(&self, columns: &[TableColumn<T>], row: &T) -> bool {
+        let search = self.search.trim();
+        if !search.is_empty() && !columns.iter().any(|column| contains(&column.filter_value(row), search)) {
+            return false;
+        }
+
+        self.columns.iter().all(|(id, filter)| {
+            columns
+                .iter()
+                .find(|column| column.id == *id)
+                .is_none_or(|column| filter.matches(column, row))
+        })
+    }
+
+    /// Serializa como query string (`q=...&f.<columna>=<tipo>:<valor>`)
+    pub fn to_query(&self) -> String {
+        let mut pairs = Vec::new();
+        if !self.search.is_empty() {
+            pairs.push(format!("q={}", encode(&self.search)));
+        }
+
+        for (id, filter) in &self.columns {
+            let value = match filter {
+                ColumnFilter::Contains(text) => format!("contains:{}", encode(text)),
+                ColumnFilter::Range { min, max } => format!(
+                    "range:{}..{}",
+                    min.map(|v| v.to_string()).unwrap_or_default(),
+                    max.map(|v| v.to_string()).unwrap_or_default()
+                ),
+                ColumnFilter::OneOf(values) => format!(
+                    "in:{}",
+                    values.iter().map(|v| encode(v)).collect::<Vec<_>>().join(",")
+                ),
+            };
+            pairs.push(format!("f.{}={}", encode(id), value));
+        }
+
+        pairs.join("&")
+    }
+
+    /// Lee el formato de `to_query`; ignora pares que no entiende
+    pub fn from_query(query: &str) -> Self {
+        let mut filters = TableFilters::default();
+
+        for pair in query.trim_start_matches('?').split('&') {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+
+            if key == "q" {
+                filters.search = decode(value);
+                continue;
+            }
+
+            let Some(id) = key.strip_prefix("f.") else {
+                continue;
+            };
+
+            let filter = match value.split_once(':') {
+                Some(("contains", text)) => Some(ColumnFilter::Contains(decode(text))),
+                Some(("range", range)) => range.split_once("..").map(|(min, max)| ColumnFilter::Range {
+                    min: min.parse().ok(),
+                    max: max.parse().ok(),
+                }),
+                Some(("in", values)) => Some(ColumnFilter::OneOf(
+                    values.split(',').filter(|v| !v.is_empty()).map(decode).collect(),
+                )),
+                _ => None,
+            };
+
+            if let Some(filter) = filter {
+                filters.set(&decode(id), Some(filter));
+            }
+        }
+
+        filters
+    }
+}
+
+fn contains(value: &str, text: &str) -> bool {
+    value.to_lowercase().contains(&text.trim().to_lowercase())
+}
+
+/// Percent-encoding de todo lo que no sea alfanumérico o `-_.~`
+pub(crate) fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+pub(crate) fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|hex| std::str::from_utf8(hex).ok());
+        match (bytes[i], hex.and_then(|hex| u8::from_str_radix(hex, 16).ok())) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Celda de la fila de filtros para una columna
+pub(crate) fn filter_cell<T>(
+    column: &TableColumn<T>,
+    filters: &TableFilters,
+    set_filters: &Callback<TableFilters>,
+) -> Html {
+    let Some(kind) = &column.filter else {
+        return html! {};
+    };
+
+    let current = filters.get(&column.id).cloned();
+    let update = |build: fn(&Option<ColumnFilter>, String) -> Option<ColumnFilter>| {
+        let filters = filters.clone();
+        let set_filters = set_filters.clone();
+        let id = column.id.clone();
+        let current = current.clone();
+        Callback::from(move |value: String| {
+            let mut next = filters.clone();
+            next.set(&id, build(&current, value));
+            set_filters.emit(next);
+        })
+    };
+
+    match kind {
+        FilterKind::Text => {
+            let value = match &current {
+                Some(ColumnFilter::Contains(text)) => text.clone(),
+                _ => String::new(),
+            };
+            html! {
+                <Input
+                    value={value}
+                    placeholder={Some("Filter…".to_string())}
+                    oninput={update(|_, value| Some(ColumnFilter::Contains(value)))}
+                />
+            }
+        }
+        FilterKind::Range => {
+            let (min, max) = match &current {
+                Some(ColumnFilter::Range { min, max }) => (*min, *max),
+                _ => (None, None),
+            };
+            let set_bound = |build: fn(Option<f64>, Option<f64>, Option<f64>) -> ColumnFilter| {
+                let filters = filters.clone();
+                let set_filters = set_filters.clone();
+                let id = column.id.clone();
+                Callback::from(move |value: Option<f64>| {
+                    let mut next = filters.clone();
+                    next.set(&id, Some(build(min, max, value)));
+                    set_filters.emit(next);
+                })
+            };
+            html! {
+                <div class="flex items-center gap-1">
+                    <RangeFilterInput
+                        value={min}
+                        placeholder="Min"
+                        onchange={set_bound(|_, max, min| ColumnFilter::Range { min, max })}
+                    />
+                    <RangeFilterInput
+                        value={max}
+                        placeholder="Max"
+                        onchange={set_bound(|min, _, max| ColumnFilter::Range { min, max })}
+                    />
+                </div>
+            }
+        }
+        FilterKind::Options(options) => {
+            let selected = match &current {
+                Some(ColumnFilter::OneOf(values)) => values.clone(),
+                _ => Vec::new(),
+            };
+            let label = match selected.len() {
+                0 => "All".to_string(),
+                1 => selected[0].clone(),
+                n => format!("{} selected", n),
+            };
+
+            html! {
+                <Dropdown trigger={html! { <span class="font-normal">{label}</span> }}>
+                    {options.iter().map(|option| {
+                        let checked = selected.contains(option);
+                        let onclick = {
+                            let toggle = update(|current, option| {
+                                let mut values = match current {
+                                    Some(ColumnFilter::OneOf(values)) => values.clone(),
+                                    _ => Vec::new(),
+                                };
+                                match values.iter().position(|v| *v == option) {
+                                    Some(idx) => {
+                                        values.remove(idx);
+                                    }
+                                    None => values.push(option),
+                                }
+                                Some(ColumnFilter::OneOf(values))
+                            });
+                            let option = option.clone();
+                            Callback::from(move |_: MouseEvent| toggle.emit(option.clone()))
+                        };
+
+                        html! {
+                            <DropdownItem onclick={onclick}>
+                                <span class="inline-flex items-center gap-2">
+                                    <span class={classes!("w-4", if checked { "text-accent" } else { "text-transparent" })}>
+                                        {"✓"}
+                                    </span>
+                                    {option}
+                                </span>
+                            </DropdownItem>
+                        }
+                    }).collect::<Html>()}
+                </Dropdown>
+            }
+        }
+    }
+}
+
+/// Filtros actuales: controlados si el parent escucha los cambios
+///
+/// Cada cambio emite también `reset_page` (volver a la primera página).
+/// Devuelve los filtros y el callback que los cambia.
+#[hook]
+pub(crate) fn use_table_filters(
+    filters: Option<TableFilters>,
+    default_filters: Option<TableFilters>,
+    onfilterchange: Option<Callback<TableFilters>>,
+    reset_page: Option<Callback<()>>,
+) -> (TableFilters, Callback<TableFilters>) {
+    let internal_filters = use_state(|| default_filters.unwrap_or_default());
+
+    let current = match onfilterchange {
+        Some(_) => filters.unwrap_or_default(),
+        None => (*internal_filters).clone(),
+    };
+    let set_filters = Callback::from(move |filters: TableFilters| {
+        match &onfilterchange {
+            Some(callback) => callback.emit(filters),
+            None => internal_filters.set(filters),
+        }
+        if let Some(reset_page) = &reset_page {
+            reset_page.emit(());
+        }
+    });
+
+    (current, set_filters)
+}
+
+/// Búsqueda global del toolbar
+pub(crate) fn search_box(filters: &TableFilters, set_filters: &Callback<TableFilters>) -> Html {
+    let onsearch = {
+        let filters = filters.clone();
+        set_filters.reform(move |search: String| filters.clone().with_search(search))
+    };
+
+    html! {
+        <div class="w-64">
+            <Input
+                input_type="search"
+                value={filters.search.clone()}
+                placeholder={Some("Search…".to_string())}
+                oninput={onsearch}
+            />
+        </div>
+    }
+}
+
+/// Fila de filtros debajo del header
+pub(crate) fn filter_row<T>(
+    columns: &[TableColumn<T>],
+    filters: &TableFilters,
+    set_filters: &Callback<TableFilters>,
+    frozen: &FrozenColumns,
+    row_index: Option<&'static str>,
+) -> Html {
+    html! {
+        <tr aria-rowindex={row_index} class="border-t border-border-subtle">
+            {frozen.leading_blank("th", "bg-bg-tertiary")}
+            {columns.iter().enumerate().map(|(col_idx, column)| {
+                let (frozen_class, frozen_style) = frozen.cell(frozen.leading() + col_idx, "bg-bg-tertiary");
+                html! {
+                    <th style={frozen_style} class={classes!("px-3", "py-2", "font-normal", "text-left", frozen_class)}>
+                        {filter_cell(column, filters, set_filters)}
+                    </th>
+                }
+            }).collect::<Html>()}
+        </tr>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct RangeFilterInputProps {
+    value: Option<f64>,
+    placeholder: AttrValue,
+    onchange: Callback<Option<f64>>,
+}
+
+/// Input de un extremo del filtro de rango
+///
+/// Guarda el texto tal cual se tipea y emite el valor solo cuando parsea,
+/// así "-" o "1." no se borran a mitad de camino. Vacío quita el extremo.
+#[function_component(RangeFilterInput)]
+fn range_filter_input(props: &RangeFilterInputProps) -> Html {
+    let format = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+    let raw = use_state_eq(|| format(props.value));
+
+    // Si el filtro cambia desde afuera (query string, limpiar filtros) el
+    // texto se sincroniza
+    {
+        let raw = raw.clone();
+        use_effect_with(props.value, move |value| {
+            let parsed = raw.trim().parse::<f64>().ok();
+            if parsed.is_none() || parsed != *value {
+                raw.set(format(*value));
+            }
+        });
+    }
+
+    let oninput = {
+        let raw = raw.clone();
+        let onchange = props.onchange.clone();
+        Callback::from(move |text: String| {
+            let trimmed = text.trim();
+            if trimmed.is_empty() {
+                onchange.emit(None);
+            } else if let Ok(value) = trimmed.parse::<f64>() {
+                onchange.emit(Some(value));
+            }
+            raw.set(text);
+        })
+    };
+
+    html! {
+        <Input
+            input_type="number"
+            value={(*raw).clone()}
+            placeholder={Some(props.placeholder.to_string())}
+            oninput={oninput}
+        />
+    }
+}
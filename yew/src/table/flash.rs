@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use super::cell::TableChangeType;
+
+/// Dirección del flash cuando una celda numérica cambia de valor
+///
+/// Sube → Bullish, baja → Bearish, sin cambio (o NaN) → None.
+///
+/// ```
+/// use hubermann_ui::{flash_direction, TableChangeType};
+///
+/// assert!(flash_direction(182.40, 182.45) == Some(TableChangeType::Bullish));
+/// assert!(flash_direction(182.45, 182.40) == Some(TableChangeType::Bearish));
+/// assert!(flash_direction(182.45, 182.45).is_none());
+/// ```
+pub fn flash_direction(previous: f64, current: f64) -> Option<TableChangeType> {
+    if current > previous {
+        Some(TableChangeType::Bullish)
+    } else if current < previous {
+        Some(TableChangeType::Bearish)
+    } else {
+        None
+    }
+}
+
+/// Clase de animación del flash (keyframes en tailwind.config.base.js)
+pub(crate) fn flash_class(change: &TableChangeType) -> &'static str {
+    match change {
+        TableChangeType::Bullish => "animate-flash-bullish motion-reduce:animate-none",
+        TableChangeType::Bearish => "animate-flash-bearish motion-reduce:animate-none",
+        TableChangeType::Neutral => "animate-flash-neutral motion-reduce:animate-none",
+    }
+}
+
+/// Key, clase y estilo de una celda según su flash
+///
+/// La generación en la key re-monta solo esta celda para reiniciar la
+/// animación; `duration` es la duración del flash en ms.
+pub(crate) fn flash_attrs(
+    column_id: &str,
+    flash: Option<&CellFlash>,
+    duration: u32,
+) -> (String, Option<&'static str>, Option<String>) {
+    match flash {
+        Some(flash) => (
+            format!("{}:{}", column_id, flash.generation),
+            Some(flash_class(&flash.change)),
+            Some(format!("animation-duration: {}ms", duration)),
+        ),
+        None => (column_id.to_string(), None, None),
+    }
+}
+
+/// Flash activo de una celda
+#[derive(Clone, PartialEq)]
+pub(crate) struct CellFlash {
+    pub change: TableChangeType,
+    /// Crece con cada cambio: como key de la celda reinicia la animación
+    pub generation: u32,
+}
+
+struct TrackedCell {
+    value: f64,
+    flash: Option<CellFlash>,
+}
+
+/// Último valor numérico de cada celda renderizada, por (row key, column id)
+///
+/// Se actualiza durante el render: `track` compara cada celda con el render
+/// anterior y `finish` descarta las que ya no se muestran (así una fila que
+/// vuelve a entrar por scroll no flashea por cambios que no se vieron).
+#[derive(Default)]
+pub(crate) struct FlashTracker {
+    cells: HashMap<(String, String), TrackedCell>,
+    next: HashMap<(String, String), TrackedCell>,
+}
+
+impl FlashTracker {
+    pub fn track(&mut self, row_key: &str, column_id: &str, value: f64) -> Option<CellFlash> {
+        let id = (row_key.to_string(), column_id.to_string());
+        let flash = match self.cells.remove(&id) {
+            Some(previous) => match flash_direction(previous.value, value) {
+                Some(change) => Some(CellFlash {
+                    change,
+                    generation: previous.flash.map_or(1, |f| f.generation.wrapping_add(1)),
+                }),
+                None => previous.flash,
+            },
+            None => None,
+        };
+
+        self.next.insert(id, TrackedCell { value, flash: flash.clone() });
+        flash
+    }
+
+    pub fn finish(&mut self) {
+        self.cells = std::mem::take(&mut self.next);
+    }
+}
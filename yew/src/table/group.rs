@@ -0,0 +1,465 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::body::BodyRow;
+use super::column::{Accessor, TableColumn};
+use super::navigation::GridNavigation;
+use super::resize::FrozenColumns;
+use super::sort::SortKey;
+use crate::accordion::accordion_chevron;
+
+/// Función de agregación de una columna
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AggregateFn {
+    Sum,
+    Avg,
+    Min,
+    Max,
+    /// Promedio ponderado por el peso de cada fila (ej: precio por cantidad)
+    WeightedAvg,
+}
+
+/// Aplica una agregación a pares (valor, peso); None si no hay filas
+///
+/// El peso solo se usa en `WeightedAvg`.
+///
+/// ```
+/// use hubermann_ui::{aggregate, AggregateFn};
+///
+/// // (precio, cantidad)
+/// let fills = [(100.0, 10.0), (110.0, 30.0)];
+///
+/// assert_eq!(aggregate(AggregateFn::Sum, &fills), Some(210.0));
+/// assert_eq!(aggregate(AggregateFn::Avg, &fills), Some(105.0));
+/// assert_eq!(aggregate(AggregateFn::Max, &fills), Some(110.0));
+/// assert_eq!(aggregate(AggregateFn::WeightedAvg, &fills), Some(107.5));
+/// assert_eq!(aggregate(AggregateFn::Min, &[]), None);
+/// ```
+pub fn aggregate(function: AggregateFn, values: &[(f64, f64)]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let sum: f64 = values.iter().map(|(value, _)| value).sum();
+    match function {
+        AggregateFn::Sum => Some(sum),
+        AggregateFn::Avg => Some(sum / values.len() as f64),
+        AggregateFn::Min => values.iter().map(|(value, _)| *value).reduce(f64::min),
+        AggregateFn::Max => values.iter().map(|(value, _)| *value).reduce(f64::max),
+        AggregateFn::WeightedAvg => {
+            let total_weight: f64 = values.iter().map(|(_, weight)| weight).sum();
+            (total_weight != 0.0)
+                .then(|| values.iter().map(|(value, weight)| value * weight).sum::<f64>() / total_weight)
+        }
+    }
+}
+
+/// Agregado de una columna en las filas de grupo y el total general
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// TableAggregate::sum("value", |p: &Position| p.qty * p.price)
+///     .with_format(|v| format!("${:.2}", v));
+/// TableAggregate::weighted_avg("price", |p: &Position| p.price, |p: &Position| p.qty);
+/// ```
+pub struct TableAggregate<T> {
+    /// Id de la columna donde se muestra
+    pub column: String,
+    pub function: AggregateFn,
+    value: Accessor<T, f64>,
+    weight: Option<Accessor<T, f64>>,
+    format: Rc<dyn Fn(f64) -> String>,
+}
+
+impl<T> TableAggregate<T> {
+    pub fn new(column: impl Into<String>, function: AggregateFn, value: impl Fn(&T) -> f64 + 'static) -> Self {
+        Self {
+            column: column.into(),
+            function,
+            value: Rc::new(value),
+            weight: None,
+            format: Rc::new(|value| format!("{:.2}", value)),
+        }
+    }
+
+    pub fn sum(column: impl Into<String>, value: impl Fn(&T) -> f64 + 'static) -> Self {
+        Self::new(column, AggregateFn::Sum, value)
+    }
+
+    pub fn avg(column: impl Into<String>, value: impl Fn(&T) -> f64 + 'static) -> Self {
+        Self::new(column, AggregateFn::Avg, value)
+    }
+
+    pub fn min(column: impl Into<String>, value: impl Fn(&T) -> f64 + 'static) -> Self {
+        Self::new(column, AggregateFn::Min, value)
+    }
+
+    pub fn max(column: impl Into<String>, value: impl Fn(&T) -> f64 + 'static) -> Self {
+        Self::new(column, AggregateFn::Max, value)
+    }
+
+    pub fn weighted_avg(
+        column: impl Into<String>,
+        value: impl Fn(&T) -> f64 + 'static,
+        weight: impl Fn(&T) -> f64 + 'static,
+    ) -> Self {
+        let mut aggregate = Self::new(column, AggregateFn::WeightedAvg, value);
+        aggregate.weight = Some(Rc::new(weight));
+        aggregate
+    }
+
+    /// Formato del valor agregado (default: 2 decimales)
+    pub fn with_format(mut self, format: impl Fn(f64) -> String + 'static) -> Self {
+        self.format = Rc::new(format);
+        self
+    }
+
+    /// Valor agregado y formateado sobre un conjunto de filas
+    pub fn compute<'a>(&self, rows: impl IntoIterator<Item = &'a T>) -> Option<String>
+    where
+        T: 'a,
+    {
+        let values: Vec<(f64, f64)> = rows
+            .into_iter()
+            .map(|row| {
+                let weight = self.weight.as_ref().map_or(1.0, |weight| weight(row));
+                ((self.value)(row), weight)
+            })
+            .collect();
+        aggregate(self.function, &values).map(|value| (self.format)(value))
+    }
+}
+
+impl<T> Clone for TableAggregate<T> {
+    fn clone(&self) -> Self {
+        Self {
+            column: self.column.clone(),
+            function: self.function,
+            value: self.value.clone(),
+            weight: self.weight.clone(),
+            format: self.format.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for TableAggregate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.column == other.column
+            && self.function == other.function
+            && Rc::ptr_eq(&self.value, &other.value)
+            && Rc::ptr_eq(&self.format, &other.format)
+            && match (&self.weight, &other.weight) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+/// Agrupamiento de filas de la tabla
+///
+/// Agrupa por el valor de un accessor (ej: el sector de cada posición),
+/// con una fila de grupo colapsable que muestra los agregados y,
+/// opcionalmente, una fila de total general al pie.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// html! {
+///     <Table<Position>
+///         columns={columns}
+///         rows={positions}
+///         grouping={Some(
+///             TableGrouping::new(|p: &Position| p.sector.clone())
+///                 .with_aggregate(TableAggregate::sum("value", |p: &Position| p.value))
+///                 .with_aggregate(TableAggregate::weighted_avg("pnl", |p: &Position| p.pnl_pct, |p: &Position| p.value))
+///                 .with_grand_total(true)
+///         )}
+///     />
+/// }
+/// ```
+pub struct TableGrouping<T> {
+    group_by: Accessor<T, String>,
+    pub aggregates: Vec<TableAggregate<T>>,
+    /// Muestra la fila de total general al pie
+    pub grand_total: bool,
+    /// Si los grupos empiezan colapsados
+    pub default_collapsed: bool,
+}
+
+impl<T> TableGrouping<T> {
+    pub fn new(group_by: impl Fn(&T) -> String + 'static) -> Self {
+        Self {
+            group_by: Rc::new(group_by),
+            aggregates: Vec::new(),
+            grand_total: false,
+            default_collapsed: false,
+        }
+    }
+
+    pub fn with_aggregate(mut self, aggregate: TableAggregate<T>) -> Self {
+        self.aggregates.push(aggregate);
+        self
+    }
+
+    pub fn with_grand_total(mut self, grand_total: bool) -> Self {
+        self.grand_total = grand_total;
+        self
+    }
+
+    pub fn with_default_collapsed(mut self, collapsed: bool) -> Self {
+        self.default_collapsed = collapsed;
+        self
+    }
+
+    pub fn group_key(&self, row: &T) -> String {
+        (self.group_by)(row)
+    }
+
+    /// Agregados por columna (en el orden de `columns`) sobre un conjunto de filas
+    pub(crate) fn aggregate_cells(&self, columns: &[TableColumn<T>], rows: &[&T]) -> Vec<Option<String>> {
+        columns
+            .iter()
+            .map(|column| {
+                self.aggregates
+                    .iter()
+                    .find(|aggregate| aggregate.column == column.id)
+                    .and_then(|aggregate| aggregate.compute(rows.iter().copied()))
+            })
+            .collect()
+    }
+}
+
+impl<T> Clone for TableGrouping<T> {
+    fn clone(&self) -> Self {
+        Self {
+            group_by: self.group_by.clone(),
+            aggregates: self.aggregates.clone(),
+            grand_total: self.grand_total,
+            default_collapsed: self.default_collapsed,
+        }
+    }
+}
+
+impl<T> PartialEq for TableGrouping<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.group_by, &other.group_by)
+            && self.aggregates == other.aggregates
+            && self.grand_total == other.grand_total
+            && self.default_collapsed == other.default_collapsed
+    }
+}
+
+/// Fila header de un grupo
+pub(crate) struct GroupHeader {
+    pub key: String,
+    pub count: usize,
+    pub collapsed: bool,
+    /// Agregado de cada columna visible
+    pub aggregates: Vec<Option<String>>,
+}
+
+/// Fila a mostrar en el body: header de grupo o fila de datos
+pub(crate) enum DisplayRow {
+    Group(GroupHeader),
+    /// Posición dentro del orden visible
+    Data(usize),
+    /// Detalle expandido de la fila en esa posición
+    Detail(usize),
+}
+
+/// Arma las filas del body agrupando `order` (ya ordenado y filtrado)
+///
+/// Dentro de cada grupo se mantiene el orden de `order`. Con `sorted` los
+/// grupos siguen ese mismo orden (primero el de la primera fila); si no, se
+/// ordenan por su key. `toggled` son los grupos con estado distinto al default.
+pub(crate) fn group_display_rows<T>(
+    grouping: &TableGrouping<T>,
+    columns: &[TableColumn<T>],
+    rows: &[T],
+    order: &[usize],
+    sorted: bool,
+    toggled: &HashSet<String>,
+) -> Vec<DisplayRow> {
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for (position, &row_idx) in order.iter().enumerate() {
+        let key = grouping.group_key(&rows[row_idx]);
+        match index.get(&key) {
+            Some(&group) => groups[group].1.push(position),
+            None => {
+                index.insert(key.clone(), groups.len());
+                groups.push((key, vec![position]));
+            }
+        }
+    }
+    // Con un sort activo los grupos quedan en el orden en que aparecen (el
+    // del sort); sin sort, por key
+    if !sorted {
+        groups.sort_by(|(a, _), (b, _)| SortKey::parse(a).compare(&SortKey::parse(b)));
+    }
+
+    let mut display = Vec::with_capacity(order.len() + groups.len());
+    for (key, positions) in groups {
+        let collapsed = grouping.default_collapsed != toggled.contains(&key);
+        let members: Vec<&T> = positions.iter().map(|&position| &rows[order[position]]).collect();
+
+        display.push(DisplayRow::Group(GroupHeader {
+            aggregates: grouping.aggregate_cells(columns, &members),
+            count: positions.len(),
+            collapsed,
+            key,
+        }));
+        if !collapsed {
+            display.extend(positions.into_iter().map(DisplayRow::Data));
+        }
+    }
+    display
+}
+
+/// Grupos con estado distinto al default (colapsado/expandido)
+#[hook]
+pub(crate) fn use_group_toggles() -> UseStateHandle<HashSet<String>> {
+    use_state(HashSet::<String>::new)
+}
+
+/// Fila header de un grupo: chevron, label y cantidad de filas más los
+/// agregados de cada columna. El label comparte la celda con el agregado
+/// de la primera columna.
+pub(crate) fn group_row<T>(
+    row: BodyRow,
+    group: &GroupHeader,
+    columns: &[TableColumn<T>],
+    toggled: &UseStateHandle<HashSet<String>>,
+    frozen: &FrozenColumns,
+    grid: &GridNavigation,
+) -> Html {
+    let toggle_group = {
+        let toggled = toggled.clone();
+        let key = group.key.clone();
+        Rc::new(move || {
+            let mut next = (*toggled).clone();
+            if !next.remove(&key) {
+                next.insert(key.clone());
+            }
+            toggled.set(next);
+        })
+    };
+    let ontoggle = {
+        let toggle_group = toggle_group.clone();
+        Callback::from(move |_: MouseEvent| toggle_group())
+    };
+    let activate: Rc<dyn Fn(usize)> = Rc::new(move |_| toggle_group());
+    let leading = frozen.leading();
+
+    html! {
+        <tr
+            key={format!("group:{}", group.key)}
+            ref={row.node_ref}
+            aria-rowindex={grid.row_index(row.index)}
+            onkeydown={grid.keydown(row.index, Some(activate), None)}
+            class={classes!("bg-bg-tertiary/50", row.border_class)}
+        >
+            {(0..leading).map(|cell_idx| {
+                let (frozen_class, frozen_style) = frozen.cell(cell_idx, "bg-bg-tertiary");
+                let cell = grid.cell(row.index, cell_idx);
+                html! {
+                    <td
+                        role={cell.role}
+                        tabindex={cell.tabindex}
+                        aria-colindex={cell.colindex}
+                        onfocusin={cell.onfocusin}
+                        style={frozen_style}
+                        class={classes!("w-10", frozen_class)}
+                    ></td>
+                }
+            }).collect::<Html>()}
+            {columns.iter().zip(&group.aggregates).enumerate().map(|(col_idx, (column, aggregate))| {
+                let (frozen_class, frozen_style) = frozen.cell(leading + col_idx, "bg-bg-tertiary");
+                let cell = grid.cell(row.index, leading + col_idx);
+                if col_idx == 0 {
+                    html! {
+                        <td
+                            role={cell.role}
+                            tabindex={cell.tabindex}
+                            aria-colindex={cell.colindex}
+                            onfocusin={cell.onfocusin}
+                            style={frozen_style}
+                            class={classes!("px-3", "py-2", frozen_class)}
+                        >
+                            <div class="flex items-center justify-between gap-3">
+                                <button
+                                    type="button"
+                                    tabindex={cell.inner_tabindex}
+                                    aria-expanded={(!group.collapsed).to_string()}
+                                    onclick={ontoggle.clone()}
+                                    class="inline-flex items-center gap-2 font-semibold text-text-primary hover:text-accent transition-colors"
+                                >
+                                    {accordion_chevron(!group.collapsed, "w-4 h-4")}
+                                    {&group.key}
+                                    <span class="text-xs font-normal text-text-tertiary">{group.count}</span>
+                                </button>
+                                if let Some(aggregate) = aggregate {
+                                    <span class="font-semibold text-text-primary">{aggregate}</span>
+                                }
+                            </div>
+                        </td>
+                    }
+                } else {
+                    html! {
+                        <td
+                            role={cell.role}
+                            tabindex={cell.tabindex}
+                            aria-colindex={cell.colindex}
+                            onfocusin={cell.onfocusin}
+                            style={frozen_style}
+                            class={classes!("px-3", "py-2", "font-semibold", "text-text-primary", column.align.class(), frozen_class)}
+                        >
+                            {aggregate.clone().unwrap_or_default()}
+                        </td>
+                    }
+                }
+            }).collect::<Html>()}
+        </tr>
+    }
+}
+
+/// Footer con el total general; el agregado de la primera columna va junto a "Total"
+pub(crate) fn grand_total_row<T>(
+    columns: &[TableColumn<T>],
+    totals: Vec<Option<String>>,
+    frozen: &FrozenColumns,
+    row_index: Option<String>,
+) -> Html {
+    let leading = frozen.leading();
+
+    html! {
+        <tfoot class="bg-bg-tertiary border-t border-border-default">
+            <tr aria-rowindex={row_index}>
+                {frozen.leading_blank("td", "bg-bg-tertiary")}
+                {columns.iter().zip(totals).enumerate().map(|(col_idx, (column, aggregate))| {
+                    let content = match (col_idx, aggregate) {
+                        (0, Some(value)) => html! {
+                            <div class="flex items-center justify-between gap-3">
+                                <span>{"Total"}</span>
+                                <span>{value}</span>
+                            </div>
+                        },
+                        (0, None) => html! { {"Total"} },
+                        (_, value) => html! { {value.unwrap_or_default()} },
+                    };
+                    let (frozen_class, frozen_style) = frozen.cell(leading + col_idx, "bg-bg-tertiary");
+                    html! {
+                        <td
+                            style={frozen_style}
+                            class={classes!("px-3", "py-3", "font-semibold", "text-text-primary", column.align.class(), frozen_class)}
+                        >
+                            {content}
+                        </td>
+                    }
+                }).collect::<Html>()}
+            </tr>
+        </tfoot>
+    }
+}
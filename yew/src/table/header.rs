@@ -0,0 +1,116 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::column::TableColumn;
+use super::layout::{ColumnReorder, HeaderDrag};
+use super::resize::{join_styles, resize_handle, FrozenColumns, ResizeDrag};
+use super::selection::{select_all_header, RowSelection};
+use super::sort::{sort_header, TableSort};
+use super::TableProps;
+
+/// Lo que comparten las celdas del header en un render
+pub(crate) struct TableHeader<'a, T: PartialEq + 'static> {
+    pub props: &'a TableProps<T>,
+    /// Columnas visibles en el orden del layout
+    pub columns: &'a [TableColumn<T>],
+    pub frozen: &'a FrozenColumns,
+    pub selection: &'a RowSelection,
+    pub expandable: bool,
+    pub sort: Option<&'a TableSort>,
+    /// Cambia el orden (y vuelve a la primera página)
+    pub set_sort: &'a Callback<Option<TableSort>>,
+    /// Anchos redimensionados por id de columna
+    pub widths: &'a [(String, f64)],
+    pub start_resize: &'a Callback<ResizeDrag>,
+    pub reorder: &'a ColumnReorder,
+}
+
+impl<T: PartialEq + 'static> TableHeader<'_, T> {
+    /// Fila de headers: selección, expansión y una celda por columna
+    pub fn row(&self) -> Html {
+        let expand_idx = usize::from(self.selection.is_enabled());
+        let (expand_frozen, expand_style) = self.frozen.cell(expand_idx, "bg-bg-tertiary");
+        // El drop reordena sobre todas las columnas, también las ocultas
+        let all_columns = self.props.reorderable.then(|| Rc::new(self.props.columns.clone()));
+
+        html! {
+            <tr aria-rowindex={self.props.keyboard_navigation.then_some("1")}>
+                // Columna de selección
+                if self.selection.is_enabled() {
+                    {select_all_header(self.selection, self.frozen)}
+                }
+                // Columna de expansión
+                if self.expandable {
+                    <th
+                        ref={self.frozen.header_ref(expand_idx)}
+                        style={expand_style}
+                        class={classes!("w-10", expand_frozen)}
+                    >
+                        <span class="sr-only">{"Details"}</span>
+                    </th>
+                }
+                {self.columns.iter().enumerate().map(|(col_idx, column)| {
+                    self.cell(all_columns.as_ref(), col_idx, column)
+                }).collect::<Html>()}
+            </tr>
+        }
+    }
+
+    /// Header de una columna: sort, ancho, drag & drop y borde de resize
+    fn cell(&self, all_columns: Option<&Rc<Vec<TableColumn<T>>>>, col_idx: usize, column: &TableColumn<T>) -> Html {
+        let props = self.props;
+        let cell_idx = self.frozen.leading() + col_idx;
+        let (frozen_class, frozen_style) = self.frozen.cell(cell_idx, "bg-bg-tertiary");
+
+        // Ancho redimensionado (px exactos) o el CSS de la columna
+        let resized = self.widths.iter().find(|(id, _)| *id == column.id).map(|(_, width)| *width);
+        let width_style = match resized {
+            Some(width) => Some(format!("width: {0}px; min-width: {0}px; max-width: {0}px", width)),
+            None => column.width.as_ref().map(|w| format!("width: {}", w)),
+        };
+        let position_class = (props.resizable && frozen_style.is_none()).then_some("relative");
+
+        let drag = match all_columns {
+            Some(all_columns) => self.reorder.handlers(all_columns, &column.id),
+            None => HeaderDrag::default(),
+        };
+        let drop_class = self.reorder.is_target(&column.id).then_some("bg-accent/10");
+
+        let resize = props.resizable.then(|| resize_handle(column, self.start_resize));
+
+        let (aria_sort, content) = if props.sortable && column.sortable {
+            let (aria_sort, content) = sort_header(column, self.sort, self.set_sort);
+            (Some(aria_sort), content)
+        } else {
+            (None, html! { {&column.header} })
+        };
+
+        html! {
+            <th
+                ref={self.frozen.header_ref(cell_idx)}
+                aria-sort={aria_sort}
+                draggable={props.reorderable.then_some("true")}
+                ondragstart={drag.ondragstart}
+                ondragover={drag.ondragover}
+                ondrop={drag.ondrop}
+                ondragend={drag.ondragend}
+                style={join_styles(width_style, frozen_style)}
+                class={classes!(
+                    "px-3",
+                    "py-3",
+                    "font-semibold",
+                    "text-text-primary",
+                    column.align.class(),
+                    props.reorderable.then_some("cursor-grab"),
+                    position_class,
+                    frozen_class,
+                    drop_class
+                )}
+            >
+                {content}
+                {resize}
+            </th>
+        }
+    }
+}
@@ -0,0 +1,415 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gloo::storage::{LocalStorage, Storage};
+use yew::prelude::*;
+
+use super::column::TableColumn;
+use super::filter::{decode, encode};
+use super::sort::{use_table_sort, SortDirection, TableSort};
+use crate::dropdown::{Dropdown, DropdownItem, DropdownPosition};
+
+/// Layout de la tabla personalizado por el usuario: orden y visibilidad de
+/// columnas, anchos redimensionados y orden de filas
+///
+/// Se serializa como texto para guardarlo en localStorage (ver `layout_key`
+/// en `Table`).
+///
+/// ```
+/// use hubermann_ui::{TableLayout, TableSort};
+///
+/// let layout = TableLayout::default()
+///     .with_order(["price", "symbol", "change"])
+///     .with_hidden(["change"])
+///     .with_width("price", 140.0)
+///     .with_sort(Some(TableSort::desc("price")));
+///
+/// let serialized = layout.serialize();
+/// assert_eq!(serialized, "order=price,symbol,change&hidden=change&w.price=140&sort=price:desc");
+/// assert_eq!(TableLayout::parse(&serialized), layout);
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TableLayout {
+    /// Ids de columnas en el orden a mostrar (las que no estén van al final)
+    pub order: Vec<String>,
+    /// Ids de columnas ocultas
+    pub hidden: Vec<String>,
+    /// Anchos redimensionados en px por id de columna
+    pub widths: Vec<(String, f64)>,
+    pub sort: Option<TableSort>,
+}
+
+impl TableLayout {
+    pub fn with_order<S: Into<String>>(mut self, order: impl IntoIterator<Item = S>) -> Self {
+        self.order = order.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_hidden<S: Into<String>>(mut self, hidden: impl IntoIterator<Item = S>) -> Self {
+        self.hidden = hidden.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_width(mut self, column: impl Into<String>, width: f64) -> Self {
+        let column = column.into();
+        self.widths.retain(|(id, _)| *id != column);
+        self.widths.push((column, width));
+        self
+    }
+
+    pub fn with_sort(mut self, sort: Option<TableSort>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    pub fn is_hidden(&self, column: &str) -> bool {
+        self.hidden.iter().any(|id| id == column)
+    }
+
+    /// Muestra u oculta una columna
+    pub fn set_hidden(&mut self, column: &str, hidden: bool) {
+        self.hidden.retain(|id| id != column);
+        if hidden {
+            self.hidden.push(column.to_string());
+        }
+    }
+
+    /// Índices de todas las columnas (visibles u ocultas) en el orden del layout
+    ///
+    /// ```
+    /// use hubermann_ui::{TableColumn, TableLayout};
+    ///
+    /// let columns = TableColumn::from_headers(vec!["Symbol".into(), "Price".into(), "Change".into()]);
+    /// let layout = TableLayout::default().with_order(["2", "0"]).with_hidden(["0"]);
+    ///
+    /// assert_eq!(layout.column_order(&columns), vec![2, 0, 1]);
+    /// assert_eq!(layout.visible_columns(&columns), vec![2, 1]);
+    /// ```
+    pub fn column_order<T>(&self, columns: &[TableColumn<T>]) -> Vec<usize> {
+        let listed = self
+            .order
+            .iter()
+            .filter_map(|id| columns.iter().position(|column| column.id == *id));
+
+        let mut indices = Vec::with_capacity(columns.len());
+        for idx in listed.chain(0..columns.len()) {
+            if !indices.contains(&idx) {
+                indices.push(idx);
+            }
+        }
+        indices
+    }
+
+    /// Índices de las columnas visibles en el orden del layout
+    pub fn visible_columns<T>(&self, columns: &[TableColumn<T>]) -> Vec<usize> {
+        self.column_order(columns)
+            .into_iter()
+            .filter(|&idx| !self.is_hidden(&columns[idx].id))
+            .collect()
+    }
+
+    /// Mueve la columna `from` a la posición de `to` (drag & drop de headers)
+    ///
+    /// ```
+    /// use hubermann_ui::{TableColumn, TableLayout};
+    ///
+    /// let columns = TableColumn::from_headers(vec!["A".into(), "B".into(), "C".into()]);
+    /// let mut layout = TableLayout::default();
+    ///
+    /// layout.move_column(&columns, "0", "2");
+    /// assert_eq!(layout.order, vec!["1", "2", "0"]);
+    /// layout.move_column(&columns, "0", "1");
+    /// assert_eq!(layout.order, vec!["0", "1", "2"]);
+    /// ```
+    pub fn move_column<T>(&mut self, columns: &[TableColumn<T>], from: &str, to: &str) {
+        let mut order: Vec<String> = self
+            .column_order(columns)
+            .into_iter()
+            .map(|idx| columns[idx].id.clone())
+            .collect();
+
+        let (Some(from_idx), Some(to_idx)) = (
+            order.iter().position(|id| id == from),
+            order.iter().position(|id| id == to),
+        ) else {
+            return;
+        };
+
+        let column = order.remove(from_idx);
+        order.insert(to_idx, column);
+        self.order = order;
+    }
+
+    /// Serializa como `order=a,b&hidden=c&w.<columna>=<px>&sort=<columna>:asc|desc`
+    pub fn serialize(&self) -> String {
+        let list = |ids: &[String]| ids.iter().map(|id| encode(id)).collect::<Vec<_>>().join(",");
+
+        let mut pairs = Vec::new();
+        if !self.order.is_empty() {
+            pairs.push(format!("order={}", list(&self.order)));
+        }
+        if !self.hidden.is_empty() {
+            pairs.push(format!("hidden={}", list(&self.hidden)));
+        }
+        for (id, width) in &self.widths {
+            pairs.push(format!("w.{}={}", encode(id), width));
+        }
+        if let Some(sort) = &self.sort {
+            let direction = match sort.direction {
+                SortDirection::Ascending => "asc",
+                SortDirection::Descending => "desc",
+            };
+            pairs.push(format!("sort={}:{}", encode(&sort.column), direction));
+        }
+
+        pairs.join("&")
+    }
+
+    /// Lee el formato de `serialize`; ignora pares que no entiende
+    pub fn parse(serialized: &str) -> Self {
+        let list = |value: &str| value.split(',').filter(|id| !id.is_empty()).map(decode).collect();
+
+        let mut layout = TableLayout::default();
+        for pair in serialized.split('&') {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+
+            match key {
+                "order" => layout.order = list(value),
+                "hidden" => layout.hidden = list(value),
+                "sort" => {
+                    layout.sort = match value.rsplit_once(':') {
+                        Some((column, "asc")) => Some(TableSort::asc(decode(column))),
+                        Some((column, "desc")) => Some(TableSort::desc(decode(column))),
+                        _ => None,
+                    }
+                }
+                _ => {
+                    if let (Some(id), Ok(width)) = (key.strip_prefix("w."), value.parse()) {
+                        layout = layout.with_width(decode(id), width);
+                    }
+                }
+            }
+        }
+
+        layout
+    }
+
+    /// Layout guardado en localStorage bajo `key`
+    pub fn load(key: &str) -> Option<Self> {
+        LocalStorage::raw()
+            .get_item(key)
+            .ok()
+            .flatten()
+            .map(|serialized| Self::parse(&serialized))
+    }
+
+    /// Guarda el layout en localStorage bajo `key`
+    pub fn save(&self, key: &str) {
+        let _ = LocalStorage::raw().set_item(key, &self.serialize());
+    }
+}
+
+/// Layout de columnas más el orden de filas que guarda
+///
+/// El layout guardado en `layout_key` tiene prioridad sobre `layout`. Un
+/// `layout` nuevo desde el parent reemplaza el actual; con sort
+/// controlado su orden se emite por `onsort`, también el restaurado al
+/// montar. Devuelve el layout, el orden actual y el callback que lo cambia
+/// (ver `use_table_sort`).
+#[hook]
+pub(crate) fn use_table_layout(
+    layout_key: Option<String>,
+    layout: Option<TableLayout>,
+    default_sort: Option<TableSort>,
+    sort: Option<TableSort>,
+    onsort: Option<Callback<Option<TableSort>>>,
+) -> (UseStateHandle<TableLayout>, Option<TableSort>, Callback<Option<TableSort>>) {
+    let restored = use_memo((), |_| {
+        layout_key
+            .as_deref()
+            .and_then(TableLayout::load)
+            .or_else(|| layout.clone())
+    });
+    let state = use_state(|| (*restored).clone().unwrap_or_default());
+    let controlled = onsort.is_some();
+    let (current_sort, set_sort) = use_table_sort(
+        state.sort.clone().or(default_sort),
+        sort.clone(),
+        onsort,
+    );
+
+    {
+        let state = state.clone();
+        let set_sort = set_sort.clone();
+        let restored_sort = restored.as_ref().as_ref().and_then(|layout| layout.sort.clone());
+        let mounted = use_mut_ref(|| false);
+        use_effect_with(layout, move |next| {
+            let was_mounted = std::mem::replace(&mut *mounted.borrow_mut(), true);
+            match (was_mounted, next) {
+                (false, _) if controlled && restored_sort.is_some() && restored_sort != sort => {
+                    set_sort.emit(restored_sort);
+                }
+                (true, Some(next)) => {
+                    set_sort.emit(next.sort.clone());
+                    state.set(next.clone());
+                }
+                _ => {}
+            }
+        });
+    }
+
+    (state, current_sort, set_sort)
+}
+
+/// Guarda en `layout_key` y emite por `onlayoutchange` cada cambio de
+/// layout (no el inicial)
+#[hook]
+pub(crate) fn use_layout_persistence(
+    layout: TableLayout,
+    layout_key: Option<String>,
+    onlayoutchange: Option<Callback<TableLayout>>,
+) {
+    let last_layout = use_mut_ref(|| None::<TableLayout>);
+
+    use_effect_with(layout, move |current| {
+        if last_layout.borrow_mut().replace(current.clone()).is_some() {
+            if let Some(key) = &layout_key {
+                current.save(key);
+            }
+            if let Some(callback) = &onlayoutchange {
+                callback.emit(current.clone());
+            }
+        }
+    });
+}
+
+/// Handlers de drag & drop de un header (None si no es reordenable)
+#[derive(Default)]
+pub(crate) struct HeaderDrag {
+    pub ondragstart: Option<Callback<DragEvent>>,
+    pub ondragover: Option<Callback<DragEvent>>,
+    pub ondrop: Option<Callback<DragEvent>>,
+    pub ondragend: Option<Callback<DragEvent>>,
+}
+
+/// Reordenamiento de columnas arrastrando los headers
+pub(crate) struct ColumnReorder {
+    layout: UseStateHandle<TableLayout>,
+    dragged: Rc<RefCell<Option<String>>>,
+    target: UseStateHandle<Option<String>>,
+}
+
+/// Estado del drag de headers: la columna arrastrada y la de destino
+#[hook]
+pub(crate) fn use_column_reorder(layout: UseStateHandle<TableLayout>) -> ColumnReorder {
+    let dragged = use_mut_ref(|| None::<String>);
+    let target = use_state_eq(|| None::<String>);
+
+    ColumnReorder { layout, dragged, target }
+}
+
+impl ColumnReorder {
+    /// Si el drag está sobre la columna `id`
+    pub fn is_target(&self, id: &str) -> bool {
+        self.target.as_deref() == Some(id)
+    }
+
+    /// Handlers del header de la columna `id`; al soltar se mueve la
+    /// columna arrastrada a su posición
+    pub fn handlers<T: 'static>(&self, columns: &Rc<Vec<TableColumn<T>>>, id: &str) -> HeaderDrag {
+        let ondragstart = {
+            let dragged = self.dragged.clone();
+            let id = id.to_string();
+            Callback::from(move |e: DragEvent| {
+                // Firefox no arranca el drag sin datos
+                if let Some(data) = e.data_transfer() {
+                    let _ = data.set_data("text/plain", &id);
+                }
+                *dragged.borrow_mut() = Some(id.clone());
+            })
+        };
+        let ondragover = {
+            let target = self.target.clone();
+            let id = id.to_string();
+            Callback::from(move |e: DragEvent| {
+                e.prevent_default();
+                target.set(Some(id.clone()));
+            })
+        };
+        let ondrop = {
+            let dragged = self.dragged.clone();
+            let target = self.target.clone();
+            let layout = self.layout.clone();
+            let columns = columns.clone();
+            let id = id.to_string();
+            Callback::from(move |e: DragEvent| {
+                e.prevent_default();
+                if let Some(from) = dragged.borrow_mut().take() {
+                    let mut next = (*layout).clone();
+                    next.move_column(&columns, &from, &id);
+                    layout.set(next);
+                }
+                target.set(None);
+            })
+        };
+        let ondragend = {
+            let dragged = self.dragged.clone();
+            let target = self.target.clone();
+            Callback::from(move |_: DragEvent| {
+                dragged.borrow_mut().take();
+                target.set(None);
+            })
+        };
+
+        HeaderDrag {
+            ondragstart: Some(ondragstart),
+            ondragover: Some(ondragover),
+            ondrop: Some(ondrop),
+            ondragend: Some(ondragend),
+        }
+    }
+}
+
+/// Menú "Columns" del toolbar para mostrar u ocultar columnas
+///
+/// `visible` es la cantidad de columnas visibles: la última no se puede
+/// ocultar.
+pub(crate) fn column_chooser<T>(
+    layout: &UseStateHandle<TableLayout>,
+    columns: &[TableColumn<T>],
+    visible: usize,
+) -> Html {
+    html! {
+        <Dropdown trigger={html! { <span>{"Columns"}</span> }} position={DropdownPosition::Right}>
+            {layout.column_order(columns).into_iter().map(|idx| {
+                let column = &columns[idx];
+                let hidden = layout.is_hidden(&column.id);
+                let locked = !hidden && visible == 1;
+                let onclick = {
+                    let layout = layout.clone();
+                    let id = column.id.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        if !locked {
+                            let mut next = (*layout).clone();
+                            next.set_hidden(&id, !hidden);
+                            layout.set(next);
+                        }
+                    })
+                };
+
+                html! {
+                    <DropdownItem onclick={onclick}>
+                        <span class={classes!("inline-flex", "items-center", "gap-2", locked.then_some("opacity-50"))}>
+                            <span class={classes!("w-4", if hidden { "text-transparent" } else { "text-accent" })}>
+                                {"✓"}
+                            </span>
+                            {&column.header}
+                        </span>
+                    </DropdownItem>
+                }
+            }).collect::<Html>()}
+        </Dropdown>
+    }
+}
@@ -0,0 +1,628 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+mod body;
+mod cell;
+mod column;
+mod detail;
+mod edit;
+mod export;
+mod filter;
+mod flash;
+mod group;
+mod header;
+mod layout;
+mod navigation;
+mod paginate;
+mod resize;
+mod selection;
+mod sort;
+mod virtualize;
+
+pub use cell::*;
+pub use column::*;
+pub use detail::*;
+pub use edit::*;
+pub use export::*;
+pub use filter::*;
+pub use flash::*;
+pub use group::*;
+pub use layout::*;
+pub use navigation::*;
+pub use paginate::*;
+pub use resize::*;
+pub use selection::*;
+pub use sort::*;
+pub use virtualize::*;
+
+use body::TableBody;
+use detail::{use_row_expansion, with_detail_rows};
+use edit::use_cell_editing;
+use export::{export_menu, use_table_export};
+use filter::{filter_row, search_box, use_table_filters};
+use flash::FlashTracker;
+use group::{grand_total_row, group_display_rows, use_group_toggles, DisplayRow};
+use header::TableHeader;
+use layout::{column_chooser, use_column_reorder, use_layout_persistence, use_table_layout};
+use navigation::use_grid_navigation;
+use paginate::{page_rows, table_pager, use_clamped_page, use_table_page};
+use resize::{use_column_resize, use_frozen_columns};
+use selection::{bulk_actions_bar, row_keys, use_row_selection};
+use sort::sorted_indices;
+use virtualize::{spacer_row, use_virtual_window};
+
+/// Table - Tabla de datos con sorting opcional
+///
+/// Componente para mostrar datos tabulares de forma clara y organizada.
+/// Ideal para listas de acciones, posiciones, transacciones, etc.
+///
+/// Las filas son structs de dominio (`T`) y cada `TableColumn<T>` define
+/// cómo renderizar su celda, así una celda faltante no desalinea la tabla.
+/// Para tablas armadas celda por celda se usa `TableRow` con
+/// `TableColumn::from_headers`.
+///
+/// Respeta el visual language:
+/// - Borders sutiles (1px, border-default)
+/// - Header con bg-tertiary para distinguir
+/// - Text sm (14px) para legibilidad
+/// - Hover states en rows
+/// - Overflow horizontal para responsive
+///
+/// # Props
+/// - `columns`: Vec<TableColumn<T>> - Definición de las columnas
+/// - `rows`: Vec<T> - Filas de datos
+/// - `hoverable`: bool - Si tiene efecto hover (default: true)
+/// - `sortable`: bool - Headers clickeables que ciclan asc/desc/sin orden (default: false)
+/// - `sort`: Option<TableSort> - Orden actual (modo controlado)
+/// - `default_sort`: Option<TableSort> - Orden inicial (modo no controlado)
+/// - `onsort`: Option<Callback<Option<TableSort>>> - Handler cuando cambia el orden
+/// - `virtualize`: Option<TableVirtualization> - Renderiza solo las filas visibles
+/// - `pagination`: Option<TablePagination> - Paginación client-side o server-side
+/// - `page`: Option<TablePage> - Página actual (modo controlado)
+/// - `onpagechange`: Option<Callback<TablePage>> - Handler cuando cambia página o tamaño
+/// - `row_key`: Option<TableRowKey<T>> - Identidad estable de cada fila
+/// - `selection`: SelectionMode - None/Single/Multi (default: None)
+/// - `selected`: Option<Vec<String>> - Keys seleccionadas (modo controlado)
+/// - `onselectionchange`: Option<Callback<Vec<String>>> - Handler cuando cambia la selección
+/// - `bulk_actions`: Option<Html> - Acciones masivas, visibles con filas seleccionadas
+/// - `flash_changes`: bool - Flash verde/rojo cuando un valor numérico sube/baja (default: false)
+/// - `flash_duration`: u32 - Duración del flash en ms (default: 1000)
+/// - `export`: Option<TableExport> - Toolbar para descargar CSV/TSV o copiar al clipboard
+/// - `searchable`: bool - Búsqueda global en el toolbar (default: false)
+/// - `filters`: Option<TableFilters> - Filtros actuales (modo controlado)
+/// - `default_filters`: Option<TableFilters> - Filtros iniciales (modo no controlado)
+/// - `onfilterchange`: Option<Callback<TableFilters>> - Handler cuando cambian los filtros
+/// - `grouping`: Option<TableGrouping<T>> - Agrupa filas con subtotales y total general
+/// - `row_detail`: Option<TableRowDetail<T>> - Contenido expandible debajo de cada fila
+/// - `expanded`: Option<Vec<String>> - Keys de filas expandidas (modo controlado)
+/// - `onexpandchange`: Option<Callback<Vec<String>>> - Handler cuando cambian las filas expandidas
+/// - `sticky_header`: bool - Header fijo al scrollear verticalmente (default: false)
+/// - `max_height`: Option<String> - Alto máximo CSS del área con scroll (ej: "480px")
+/// - `frozen_columns`: usize - Cantidad de columnas congeladas a la izquierda (default: 0)
+/// - `resizable`: bool - Bordes de header arrastrables para cambiar el ancho (default: false)
+/// - `column_widths`: Vec<(String, f64)> - Anchos en px por id de columna (ej: guardados)
+/// - `oncolumnresize`: Option<Callback<Vec<(String, f64)>>> - Handler al terminar de redimensionar
+/// - `column_chooser`: bool - Menú "Columns" en el toolbar para mostrar/ocultar (default: false)
+/// - `reorderable`: bool - Reordenar columnas arrastrando los headers (default: false)
+/// - `layout`: Option<TableLayout> - Layout inicial (orden, visibilidad, anchos, sort)
+/// - `layout_key`: Option<String> - Key de localStorage donde se guarda/restaura el layout
+/// - `onlayoutchange`: Option<Callback<TableLayout>> - Handler cuando cambia el layout
+/// - `oncelledit`: Option<Callback<CellEdit>> - Handler al confirmar una edición inline
+/// - `keyboard_navigation`: bool - Modo grid navegable con teclado (default: false)
+/// - `onrowactivate`: Option<Callback<String>> - Handler de Enter sobre una fila (recibe su key)
+///
+/// # Sorting
+/// Cada columna ordena por su accessor `with_sort_by`, o si no tiene, por la
+/// `SortKey` de la celda renderizada: "$182.45", "+2.3%" o "45.2M" se ordenan
+/// como números, el resto como texto.
+///
+/// Si se pasa `onsort` la tabla es controlada: muestra el orden de `sort`
+/// y el parent decide qué hacer con cada cambio. Sin `onsort` la tabla
+/// maneja su propio estado arrancando en `default_sort`. La misma regla
+/// vale para página, filtros, selección y expansión: cada uno es
+/// controlado si se pasa su callback (`onpagechange`, `onfilterchange`,
+/// `onselectionchange`, `onexpandchange`), y entonces el valor sale de la
+/// prop correspondiente.
+///
+/// # Virtualización
+/// Para miles de filas (historial de fills, ticks) `virtualize` monta solo
+/// la ventana visible dentro de un contenedor con scroll y header sticky.
+/// Ver `TableVirtualization`.
+///
+/// # Paginación
+/// Con `pagination` se muestra un `Pagination` debajo de la tabla. En modo
+/// client-side la tabla corta la página de las filas ya ordenadas; en modo
+/// server-side `rows` es la página actual y cada cambio se emite por
+/// `onpagechange` para que el parent haga el fetch. Igual que el sort, la
+/// página es controlada si se pasa `onpagechange`. Ver `TablePagination`.
+///
+/// # Selección
+/// Con `selection` se agrega una columna de checkboxes (o radios en Single).
+/// En Multi el header tiene "select all" sobre las filas mostradas (con
+/// estado indeterminado) y shift-click selecciona el rango desde la última
+/// fila clickeada. La selección se identifica por `row_key`, así sobrevive a
+/// reordenamientos. Igual que el sort, la selección es controlada si se
+/// pasa `onselectionchange`: la tabla muestra `selected` y el parent
+/// guarda cada cambio.
+///
+/// # Datos en vivo
+/// Las filas van con key (`row_key`), así en cada tick Yew solo actualiza
+/// las celdas que cambiaron y no re-monta filas: el scroll y la selección
+/// se mantienen. Con `flash_changes` cada celda numérica que sube o baja
+/// respecto del render anterior hace un flash con el color de
+/// `TableChangeType` (requiere las animaciones `flash-*` del Tailwind base).
+///
+/// # Filtros
+/// Las columnas con `with_filter` muestran un filtro debajo del header:
+/// texto que contiene, rango numérico min/max u opciones multi-select.
+/// `searchable` agrega una búsqueda global (sobre el valor de filtro de
+/// todas las columnas) y el toolbar muestra "N of M rows". Igual que el
+/// sort, los filtros son controlados si se pasa `onfilterchange`; el estado
+/// (`TableFilters`) se serializa con `to_query` para guardarlo en la URL.
+/// Con paginación server-side la tabla no filtra: el parent recibe los
+/// filtros y hace el fetch. Ocultar una columna no quita su filtro: sigue
+/// aplicando aunque no se vea su input.
+///
+/// # Agrupamiento
+/// Con `grouping` las filas se agrupan por un accessor: cada grupo tiene
+/// una fila header colapsable (con el chevron del Accordion) que muestra
+/// la cantidad de filas y los agregados de cada columna (sum, avg, min,
+/// max, promedio ponderado). `with_grand_total` agrega el total general
+/// en el footer, calculado sobre todas las filas filtradas. El agregado de
+/// la primera columna va junto al label del grupo (o a "Total"). Con un
+/// sort activo las filas de cada grupo y los grupos siguen ese orden; sin
+/// sort los grupos van por key. Con paginación los grupos se arman dentro
+/// de cada página. Ver `TableGrouping`.
+///
+/// # Filas expandibles
+/// Con `row_detail` se agrega una columna con chevron: al expandir una fila
+/// se muestra su detalle debajo, ocupando todas las columnas. El chevron es
+/// un botón (Enter/Space hacen toggle, ArrowRight expande y ArrowLeft
+/// colapsa). Igual que la selección, la expansión se identifica por
+/// `row_key` y es controlada si se pasa `onexpandchange`.
+///
+/// # Tablas anchas
+/// Con `max_height` la tabla scrollea dentro de su contenedor y
+/// `sticky_header` mantiene el header visible (la virtualización ya lo
+/// hace). `frozen_columns` congela las primeras N columnas (más las de
+/// selección/expansión) al scrollear horizontalmente. Con `resizable` cada
+/// header tiene un borde arrastrable respetando `min_width`/`max_width` de
+/// la columna; al soltar se emiten todos los anchos por `oncolumnresize`
+/// para persistirlos y volver a pasarlos en `column_widths`.
+///
+/// # Layout de columnas
+/// Con `column_chooser` el toolbar muestra un `Dropdown` para mostrar u
+/// ocultar columnas (siempre queda al menos una) y con `reorderable` los
+/// headers se pueden arrastrar para cambiar el orden. Orden, visibilidad,
+/// anchos y sort forman un `TableLayout`: con `layout_key` se guarda en
+/// localStorage en cada cambio y se restaura al montar (tiene prioridad
+/// sobre `layout`). Pasar un `layout` nuevo lo aplica (ej: "Reset layout").
+/// Con sort controlado, el orden restaurado se emite por `onsort` al
+/// montar. El tamaño de página no es parte del layout: con `onpagechange`
+/// lo persiste el parent.
+///
+/// # Edición inline
+/// Las columnas con `with_editor` son editables cuando hay `oncelledit`:
+/// doble click o Enter abre un `Input`/`Select` en la celda, Enter o Tab
+/// confirman y Escape cancela. Si el validator de la columna rechaza el
+/// valor el editor queda abierto con el error (mismo estilo que `Input`).
+/// La tabla no modifica `rows`: el parent aplica el `CellEdit` (key de
+/// fila, id de columna y valor nuevo).
+///
+/// # Navegación con teclado
+/// Con `keyboard_navigation` la tabla es un `role="grid"` con una celda
+/// activa (tabindex roving): Tab entra y sale del grid, las flechas
+/// mueven la celda, Home/End van al principio/fin de la fila (con Ctrl, de
+/// la tabla) y PageUp/PageDown saltan una pantalla. Enter activa: abre el
+/// editor, alterna grupo/detalle o emite `onrowactivate`; Space selecciona
+/// la fila (Shift+Space extiende el rango). Las filas llevan
+/// `aria-rowindex` y las celdas `aria-colindex`.
+///
+/// # Exportación
+/// Con `export` el toolbar muestra un menú para descargar CSV/TSV o copiar
+/// TSV al clipboard (para pegar en una planilla). Se exportan todas las
+/// filas ordenadas; las celdas numéricas con su valor crudo. Ver `export_rows`.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use hubermann_ui::*;
+///
+/// #[derive(PartialEq)]
+/// struct Quote { symbol: String, price: f64, change: f64 }
+///
+/// let columns = vec![
+///     TableColumn::new("symbol", "Symbol", |q: &Quote| TableCell::primary(&q.symbol)),
+///     TableColumn::new("price", "Price", |q: &Quote| TableCell::text(format!("${:.2}", q.price)))
+///         .with_align(TableAlign::Right),
+///     TableColumn::new("change", "Change", |q: &Quote| {
+///         TableCell::change(format!("{:+.1}%", q.change), TableChangeType::Bullish)
+///     }),
+/// ];
+///
+/// html! {
+///     <Table<Quote>
+///         columns={columns}
+///         rows={quotes}
+///         sortable={true}
+///         default_sort={Some(TableSort::desc("change"))}
+///     />
+/// }
+/// ```
+#[derive(Properties, PartialEq)]
+pub struct TableProps<T: PartialEq + 'static> {
+    pub columns: Vec<TableColumn<T>>,
+    pub rows: Vec<T>,
+    #[prop_or(true)]
+    pub hoverable: bool,
+    #[prop_or(false)]
+    pub sortable: bool,
+    #[prop_or_default]
+    pub sort: Option<TableSort>,
+    #[prop_or_default]
+    pub default_sort: Option<TableSort>,
+    #[prop_or_default]
+    pub onsort: Option<Callback<Option<TableSort>>>,
+    #[prop_or_default]
+    pub virtualize: Option<TableVirtualization>,
+    #[prop_or_default]
+    pub pagination: Option<TablePagination>,
+    #[prop_or_default]
+    pub page: Option<TablePage>,
+    #[prop_or_default]
+    pub onpagechange: Option<Callback<TablePage>>,
+    #[prop_or_default]
+    pub row_key: Option<TableRowKey<T>>,
+    #[prop_or(SelectionMode::None)]
+    pub selection: SelectionMode,
+    #[prop_or_default]
+    pub selected: Option<Vec<String>>,
+    #[prop_or_default]
+    pub onselectionchange: Option<Callback<Vec<String>>>,
+    #[prop_or_default]
+    pub bulk_actions: Option<Html>,
+    #[prop_or(false)]
+    pub flash_changes: bool,
+    #[prop_or(1000)]
+    pub flash_duration: u32,
+    #[prop_or_default]
+    pub export: Option<TableExport>,
+    #[prop_or(false)]
+    pub searchable: bool,
+    #[prop_or_default]
+    pub filters: Option<TableFilters>,
+    #[prop_or_default]
+    pub default_filters: Option<TableFilters>,
+    #[prop_or_default]
+    pub onfilterchange: Option<Callback<TableFilters>>,
+    #[prop_or_default]
+    pub grouping: Option<TableGrouping<T>>,
+    #[prop_or_default]
+    pub row_detail: Option<TableRowDetail<T>>,
+    #[prop_or_default]
+    pub expanded: Option<Vec<String>>,
+    #[prop_or_default]
+    pub onexpandchange: Option<Callback<Vec<String>>>,
+    #[prop_or(false)]
+    pub sticky_header: bool,
+    #[prop_or_default]
+    pub max_height: Option<String>,
+    #[prop_or(0)]
+    pub frozen_columns: usize,
+    #[prop_or(false)]
+    pub resizable: bool,
+    #[prop_or_default]
+    pub column_widths: Vec<(String, f64)>,
+    #[prop_or_default]
+    pub oncolumnresize: Option<Callback<Vec<(String, f64)>>>,
+    #[prop_or(false)]
+    pub column_chooser: bool,
+    #[prop_or(false)]
+    pub reorderable: bool,
+    #[prop_or_default]
+    pub layout: Option<TableLayout>,
+    #[prop_or_default]
+    pub layout_key: Option<String>,
+    #[prop_or_default]
+    pub onlayoutchange: Option<Callback<TableLayout>>,
+    #[prop_or_default]
+    pub oncelledit: Option<Callback<CellEdit>>,
+    #[prop_or(false)]
+    pub keyboard_navigation: bool,
+    #[prop_or_default]
+    pub onrowactivate: Option<Callback<String>>,
+}
+
+#[function_component(Table)]
+pub fn table<T: PartialEq + 'static>(props: &TableProps<T>) -> Html {
+    let (layout, current_sort, set_sort) = use_table_layout(
+        props.layout_key.clone(),
+        props.layout.clone(),
+        props.default_sort.clone(),
+        props.sort.clone(),
+        props.onsort.clone(),
+    );
+
+    // Columnas visibles en el orden del layout
+    let columns: Vec<TableColumn<T>> = layout
+        .visible_columns(&props.columns)
+        .into_iter()
+        .map(|idx| props.columns[idx].clone())
+        .collect();
+
+    let mut order: Vec<usize> = if props.sortable {
+        sorted_indices(&props.rows, &props.columns, current_sort.as_ref())
+    } else {
+        (0..props.rows.len()).collect()
+    };
+
+    let (requested_page, set_page) =
+        use_table_page(props.pagination.clone(), props.page.clone(), props.onpagechange.clone());
+
+    // Al filtrar se vuelve a la primera página
+    let first_page = props.pagination.as_ref().map(|_| {
+        let page_size = requested_page.page_size;
+        set_page.reform(move |_: ()| TablePage::new(1, page_size))
+    });
+    let (current_filters, set_filters) = use_table_filters(
+        props.filters.clone(),
+        props.default_filters.clone(),
+        props.onfilterchange.clone(),
+        first_page.clone(),
+    );
+
+    let server_side = props.pagination.as_ref().is_some_and(|p| p.total.is_some());
+    if !server_side && current_filters.is_active() {
+        // Contra todas las columnas: un filtro sobre una columna oculta sigue aplicando
+        order.retain(|&idx| current_filters.matches(&props.columns, &props.rows[idx]));
+    }
+
+    // Una página fuera de rango (por ejemplo después de filtrar) se ajusta a
+    // la última; el slice, el pager y los aria-rowindex usan la misma
+    let current_page = use_clamped_page(
+        requested_page,
+        props.pagination.as_ref().map(|pagination| pagination.total.unwrap_or(order.len())),
+        set_page.clone(),
+        props.onpagechange.is_some(),
+    );
+
+    // Al reordenar también se vuelve a la primera página
+    let sort_and_reset = {
+        let set_sort = set_sort.clone();
+        let reset_page = first_page.filter(|_| current_page.page != 1);
+        Callback::from(move |next: Option<TableSort>| {
+            set_sort.emit(next);
+            if let Some(reset_page) = &reset_page {
+                reset_page.emit(());
+            }
+        })
+    };
+
+    let has_column_filters = columns.iter().any(|column| column.filter.is_some());
+    let row_summary = (!server_side && (props.searchable || has_column_filters))
+        .then(|| format!("{} of {} rows", order.len(), props.rows.len()));
+
+    let export = use_table_export(
+        props.export.as_ref().map(|export| export.filename.clone()).unwrap_or_default(),
+        &columns,
+        &props.rows,
+        &order,
+    );
+
+    // Total general sobre todas las filas filtradas (no solo la página)
+    let grand_total = props
+        .grouping
+        .as_ref()
+        .filter(|grouping| grouping.grand_total)
+        .map(|grouping| {
+            let rows: Vec<&T> = order.iter().map(|&idx| &props.rows[idx]).collect();
+            grouping.aggregate_cells(&columns, &rows)
+        });
+
+    // En client-side se muestra solo la página actual de las filas ordenadas
+    let (order, total_rows) = page_rows(order, props.pagination.as_ref(), &current_page);
+
+    // Identidad de cada fila: row_key o su posición en `rows`
+    let keys = row_keys(&props.rows, props.row_key.as_ref());
+
+    let visible_keys: Rc<Vec<String>> = Rc::new(order.iter().map(|&idx| keys[idx].clone()).collect());
+    let selection = use_row_selection(
+        props.selection,
+        props.selected.clone(),
+        props.onselectionchange.clone(),
+        visible_keys,
+    );
+    let expansion = use_row_expansion(
+        props.row_detail.is_some(),
+        props.expanded.clone(),
+        props.onexpandchange.clone(),
+    );
+
+    let leading_cells = usize::from(selection.is_enabled()) + usize::from(expansion.enabled);
+    let column_count = columns.len() + leading_cells;
+
+    // Anchos redimensionados por el usuario
+    let initial_widths = match props.column_widths.is_empty() {
+        true => layout.widths.clone(),
+        false => props.column_widths.clone(),
+    };
+    let (column_widths, start_resize) = use_column_resize(initial_widths, props.oncolumnresize.clone());
+
+    use_layout_persistence(
+        TableLayout {
+            order: layout.order.clone(),
+            hidden: layout.hidden.clone(),
+            widths: (*column_widths).clone(),
+            sort: current_sort.clone(),
+        },
+        props.layout_key.clone(),
+        props.onlayoutchange.clone(),
+    );
+
+    let reorder = use_column_reorder(layout.clone());
+    let frozen = use_frozen_columns(props.frozen_columns, leading_cells, column_count);
+
+    // Grupos (con su estado colapsado) y el detalle de las filas expandidas
+    let toggled_groups = use_group_toggles();
+    let display: Vec<DisplayRow> = match &props.grouping {
+        Some(grouping) => group_display_rows(
+            grouping,
+            &columns,
+            &props.rows,
+            &order,
+            props.sortable && current_sort.is_some(),
+            &toggled_groups,
+        ),
+        None => (0..order.len()).map(DisplayRow::Data).collect(),
+    };
+    let display = with_detail_rows(display, |position| expansion.is_expanded(&keys[order[position]]));
+
+    let window = use_virtual_window(props.virtualize.clone(), display.len());
+
+    // Flash de celdas numéricas que cambiaron desde el render anterior
+    let flash_tracker = use_mut_ref(FlashTracker::default);
+
+    // Edición inline (una celda a la vez)
+    let (editing, editor_ref) = use_cell_editing();
+
+    // aria-rowindex cuenta las filas de header y las páginas anteriores
+    let page_offset = match &props.pagination {
+        Some(_) => current_page.page.saturating_sub(1) * current_page.page_size,
+        None => 0,
+    };
+    let header_rows = 1 + usize::from(has_column_filters);
+    let body_row_count = match &props.pagination {
+        Some(_) => total_rows.max(page_offset + display.len()),
+        None => display.len(),
+    };
+    let grid = use_grid_navigation(
+        props.keyboard_navigation,
+        display.len(),
+        column_count,
+        header_rows + page_offset,
+        editing.is_some(),
+        props.virtualize.as_ref().map(|virtualize| (virtualize.height, window.row_height)),
+    );
+
+    let (container_class, container_style) = match (&props.virtualize, &props.max_height) {
+        (Some(virtualize), _) => ("overflow-auto", Some(format!("height: {}px", virtualize.height))),
+        (None, Some(max_height)) => ("overflow-auto", Some(format!("max-height: {}", max_height))),
+        (None, None) => ("overflow-x-auto", None),
+    };
+    let thead_class = if props.virtualize.is_some() || props.sticky_header {
+        "sticky top-0 z-20 bg-bg-tertiary border-b border-border-default"
+    } else {
+        "bg-bg-tertiary border-b border-border-default"
+    };
+
+    let column_menu = props
+        .column_chooser
+        .then(|| column_chooser(&layout, &props.columns, columns.len()));
+    let export_menu = props.export.as_ref().map(|_| export_menu(&export));
+
+    let header = TableHeader {
+        props,
+        columns: &columns,
+        frozen: &frozen,
+        selection: &selection,
+        expandable: expansion.enabled,
+        sort: current_sort.as_ref(),
+        set_sort: &sort_and_reset,
+        widths: &column_widths,
+        start_resize: &start_resize,
+        reorder: &reorder,
+    };
+    let body_rows = TableBody {
+        props,
+        columns: &columns,
+        order: &order,
+        keys: &keys,
+        frozen: &frozen,
+        grid: &grid,
+        selection: &selection,
+        expansion: &expansion,
+        toggled_groups: &toggled_groups,
+        editing: &editing,
+        editor_ref: &editor_ref,
+        flash_tracker: &flash_tracker,
+    }
+    .rows(&display, &window);
+
+    html! {
+        <div class="w-full border border-border-default rounded-md overflow-hidden bg-bg-secondary">
+            // Barra de acciones masivas
+            if let Some(bulk_actions) = &props.bulk_actions {
+                {bulk_actions_bar(&selection, bulk_actions)}
+            }
+
+            // Toolbar
+            if props.searchable || row_summary.is_some() || column_menu.is_some() || export_menu.is_some() {
+                <div class="flex flex-wrap items-center justify-between gap-3 px-3 py-2 border-b border-border-default">
+                    <div class="flex items-center gap-3">
+                        if props.searchable {
+                            {search_box(&current_filters, &set_filters)}
+                        }
+                        if let Some(summary) = row_summary {
+                            <span class="text-xs text-text-tertiary">{summary}</span>
+                        }
+                    </div>
+                    <div class="flex items-center gap-2">
+                        if let Some(column_menu) = column_menu {
+                            {column_menu}
+                        }
+                        if let Some(export_menu) = export_menu {
+                            {export_menu}
+                        }
+                    </div>
+                </div>
+            }
+
+            <div
+                ref={grid.container_ref.clone()}
+                class={container_class}
+                style={container_style}
+                onscroll={window.onscroll.clone()}
+            >
+                <table
+                    role={grid.enabled.then_some("grid")}
+                    aria-rowcount={grid.enabled.then(|| (header_rows + body_row_count + usize::from(grand_total.is_some())).to_string())}
+                    aria-colcount={grid.enabled.then(|| column_count.to_string())}
+                    aria-multiselectable={(grid.enabled && props.selection == SelectionMode::Multi).then_some("true")}
+                    class={classes!("w-full", "text-sm", props.resizable.then_some("table-fixed"))}
+                >
+                    // Header
+                    <thead class={thead_class}>
+                        {header.row()}
+
+                        // Fila de filtros
+                        if has_column_filters {
+                            {filter_row(&columns, &current_filters, &set_filters, &frozen, grid.enabled.then_some("2"))}
+                        }
+                    </thead>
+
+                    // Body
+                    <tbody>
+                        {spacer_row(window.range.start, window.row_height, column_count)}
+                        {body_rows}
+                        {spacer_row(display.len() - window.range.end, window.row_height, column_count)}
+                    </tbody>
+
+                    // Total general
+                    if let Some(grand_total) = grand_total {
+                        {grand_total_row(
+                            &columns,
+                            grand_total,
+                            &frozen,
+                            grid.enabled.then(|| (header_rows + body_row_count + 1).to_string()),
+                        )}
+                    }
+                </table>
+            </div>
+
+            // Paginación
+            if let Some(pagination) = &props.pagination {
+                {table_pager(pagination, &current_page, total_rows, &set_page)}
+            }
+        </div>
+    }
+}
@@ -0,0 +1,256 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+/// Próxima celda activa del grid para una tecla
+///
+/// `position` es (fila, columna) dentro del grid de `rows` x `columns`;
+/// `page` es cuántas filas salta PageUp/PageDown. Home/End van al
+/// principio/fin de la fila, o de la tabla con Ctrl. Devuelve None si la
+/// tecla no es de navegación.
+///
+/// ```
+/// use hubermann_ui::navigate_grid;
+///
+/// // Grid de 100 filas x 5 columnas, páginas de 10
+/// assert_eq!(navigate_grid((0, 0), "ArrowDown", false, 100, 5, 10), Some((1, 0)));
+/// assert_eq!(navigate_grid((0, 0), "ArrowUp", false, 100, 5, 10), Some((0, 0)));
+/// assert_eq!(navigate_grid((3, 4), "ArrowRight", false, 100, 5, 10), Some((3, 4)));
+/// assert_eq!(navigate_grid((3, 2), "End", false, 100, 5, 10), Some((3, 4)));
+/// assert_eq!(navigate_grid((3, 2), "End", true, 100, 5, 10), Some((99, 4)));
+/// assert_eq!(navigate_grid((95, 1), "PageDown", false, 100, 5, 10), Some((99, 1)));
+/// assert_eq!(navigate_grid((3, 2), "a", false, 100, 5, 10), None);
+/// ```
+pub fn navigate_grid(
+    position: (usize, usize),
+    key: &str,
+    ctrl: bool,
+    rows: usize,
+    columns: usize,
+    page: usize,
+) -> Option<(usize, usize)> {
+    if rows == 0 || columns == 0 {
+        return None;
+    }
+
+    let (row, column) = (position.0.min(rows - 1), position.1.min(columns - 1));
+    let (last_row, last_column) = (rows - 1, columns - 1);
+    let page = page.max(1);
+
+    match key {
+        "ArrowUp" => Some((row.saturating_sub(1), column)),
+        "ArrowDown" => Some(((row + 1).min(last_row), column)),
+        "ArrowLeft" => Some((row, column.saturating_sub(1))),
+        "ArrowRight" => Some((row, (column + 1).min(last_column))),
+        "Home" if ctrl => Some((0, 0)),
+        "Home" => Some((row, 0)),
+        "End" if ctrl => Some((last_row, last_column)),
+        "End" => Some((row, last_column)),
+        "PageUp" => Some((row.saturating_sub(page), column)),
+        "PageDown" => Some(((row + page).min(last_row), column)),
+        _ => None,
+    }
+}
+
+/// Atributos de una celda del grid (todos None sin `keyboard_navigation`)
+pub(crate) struct GridCell {
+    pub role: Option<&'static str>,
+    pub tabindex: Option<&'static str>,
+    pub colindex: Option<String>,
+    pub onfocusin: Option<Callback<FocusEvent>>,
+    /// tabindex de los controles dentro de la celda (fuera del orden de Tab)
+    pub inner_tabindex: Option<&'static str>,
+}
+
+/// Modo grid: celda activa (fila del body, celda) con tabindex roving
+pub(crate) struct GridNavigation {
+    pub enabled: bool,
+    /// Contenedor con scroll donde se buscan las celdas a enfocar
+    pub container_ref: NodeRef,
+    active_cell: UseStateHandle<(usize, usize)>,
+    active: (usize, usize),
+    pending_focus: Rc<RefCell<bool>>,
+    rows: usize,
+    columns: usize,
+    first_row: usize,
+    page_rows: usize,
+    /// Alto del viewport y de fila con virtualización
+    viewport: Option<(f64, f64)>,
+}
+
+/// Celda activa y foco del grid
+///
+/// `rows` x `columns` es el grid del body; `first_row` son las filas
+/// anteriores para `aria-rowindex` (headers y páginas previas). Con
+/// `viewport` (alto del contenedor y de fila) la navegación scrollea para
+/// montar la fila destino. Al cerrar el editor (`editing` pasa a false)
+/// el foco vuelve a la celda activa.
+#[hook]
+pub(crate) fn use_grid_navigation(
+    enabled: bool,
+    rows: usize,
+    columns: usize,
+    first_row: usize,
+    editing: bool,
+    viewport: Option<(f64, f64)>,
+) -> GridNavigation {
+    let active_cell = use_state_eq(|| (0usize, 0usize));
+    let active = (
+        active_cell.0.min(rows.saturating_sub(1)),
+        active_cell.1.min(columns.saturating_sub(1)),
+    );
+    let pending_focus = use_mut_ref(|| false);
+    let container_ref = use_node_ref();
+
+    {
+        let was_editing = use_mut_ref(|| false);
+        let pending_focus = pending_focus.clone();
+        use_effect_with(editing, move |&open| {
+            if std::mem::replace(&mut *was_editing.borrow_mut(), open) && !open && enabled {
+                *pending_focus.borrow_mut() = true;
+            }
+        });
+    }
+    {
+        // El foco se aplica cuando la fila activa ya está montada (virtualización)
+        let pending_focus = pending_focus.clone();
+        let container_ref = container_ref.clone();
+        let (row, column) = (first_row + active.0 + 1, active.1 + 1);
+        use_effect(move || {
+            if !*pending_focus.borrow() {
+                return;
+            }
+            let cell = container_ref
+                .cast::<HtmlElement>()
+                .and_then(|container| {
+                    let find = |selector: String| container.query_selector(&selector).ok().flatten();
+                    find(format!(r#"[aria-rowindex="{}"] > [aria-colindex="{}"]"#, row, column))
+                        .or_else(|| find(format!(r#"[aria-rowindex="{}"] > td"#, row)))
+                })
+                .and_then(|cell| cell.dyn_into::<HtmlElement>().ok());
+            if let Some(cell) = cell {
+                let _ = cell.focus();
+                *pending_focus.borrow_mut() = false;
+            }
+        });
+    }
+
+    let page_rows = match viewport {
+        Some((height, row_height)) if row_height > 0.0 => ((height / row_height) as usize).max(1),
+        _ => 10,
+    };
+
+    GridNavigation {
+        enabled,
+        container_ref,
+        active_cell,
+        active,
+        pending_focus,
+        rows,
+        columns,
+        first_row,
+        page_rows,
+        viewport,
+    }
+}
+
+impl GridNavigation {
+    /// `aria-rowindex` de una fila del body
+    pub fn row_index(&self, display_idx: usize) -> Option<String> {
+        self.enabled.then(|| (self.first_row + display_idx + 1).to_string())
+    }
+
+    /// tabindex, aria-colindex y foco de una celda
+    pub fn cell(&self, display_idx: usize, cell_idx: usize) -> GridCell {
+        if !self.enabled {
+            return GridCell {
+                role: None,
+                tabindex: None,
+                colindex: None,
+                onfocusin: None,
+                inner_tabindex: None,
+            };
+        }
+
+        let tabindex = if (display_idx, cell_idx) == self.active { "0" } else { "-1" };
+        let onfocusin = {
+            let active_cell = self.active_cell.clone();
+            Callback::from(move |_: FocusEvent| active_cell.set((display_idx, cell_idx)))
+        };
+        GridCell {
+            role: Some("gridcell"),
+            tabindex: Some(tabindex),
+            colindex: Some((cell_idx + 1).to_string()),
+            onfocusin: Some(onfocusin),
+            inner_tabindex: Some("-1"),
+        }
+    }
+
+    /// Teclado de una fila: Enter activa, Space selecciona, el resto navega
+    ///
+    /// `activate` recibe la celda activa; `select` si Shift está apretado.
+    pub fn keydown(
+        &self,
+        display_idx: usize,
+        activate: Option<Rc<dyn Fn(usize)>>,
+        select: Option<Rc<dyn Fn(bool)>>,
+    ) -> Option<Callback<KeyboardEvent>> {
+        self.enabled.then(|| {
+            let active_cell = self.active_cell.clone();
+            let pending_focus = self.pending_focus.clone();
+            let container_ref = self.container_ref.clone();
+            let viewport = self.viewport;
+            let (rows, columns, page_rows) = (self.rows, self.columns, self.page_rows);
+            let column = self.active.1;
+
+            Callback::from(move |e: KeyboardEvent| {
+                // Ya lo manejó la celda (ej: Enter abre el editor)
+                if e.default_prevented() {
+                    return;
+                }
+                match e.key().as_str() {
+                    "Enter" => {
+                        if let Some(activate) = &activate {
+                            e.prevent_default();
+                            activate(column);
+                        }
+                    }
+                    " " => {
+                        if let Some(select) = &select {
+                            e.prevent_default();
+                            select(e.shift_key());
+                        }
+                    }
+                    key => {
+                        let ctrl = e.ctrl_key() || e.meta_key();
+                        let Some(next) = navigate_grid((display_idx, column), key, ctrl, rows, columns, page_rows)
+                        else {
+                            return;
+                        };
+                        e.prevent_default();
+
+                        // Virtualizada: scrollear para que se monte la fila destino
+                        if let (Some((height, row_height)), Some(container)) =
+                            (viewport, container_ref.cast::<HtmlElement>())
+                        {
+                            let top = next.0 as f64 * row_height;
+                            let scroll = container.scroll_top() as f64;
+                            // El header sticky ocupa aproximadamente una fila
+                            if top < scroll {
+                                container.set_scroll_top(top as i32);
+                            } else if top + 2.0 * row_height > scroll + height {
+                                container.set_scroll_top((top + 2.0 * row_height - height) as i32);
+                            }
+                        }
+
+                        *pending_focus.borrow_mut() = true;
+                        active_cell.set(next);
+                    }
+                }
+            })
+        })
+    }
+}
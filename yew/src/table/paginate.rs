@@ -0,0 +1,204 @@
+use std::ops::Range;
+
+use yew::prelude::*;
+
+use crate::pagination::{default_page_size_options, page_count, Pagination};
+
+/// Configuración de paginación de la tabla
+///
+/// - Client-side (`TablePagination::client`): la tabla recibe todas las filas,
+///   las ordena y muestra solo la página actual.
+/// - Server-side (`TablePagination::server`): `rows` ya es la página actual y
+///   `total` el conteo completo; el parent hace el fetch en `onpagechange`.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// // Client-side, estado interno
+/// html! {
+///     <Table<Trade> columns={columns} rows={trades} pagination={Some(TablePagination::client(25))} />
+/// }
+///
+/// // Server-side, estado en el parent
+/// html! {
+///     <Table<Trade>
+///         columns={columns}
+///         rows={(*page_rows).clone()}
+///         pagination={Some(TablePagination::server(total_count, 50))}
+///         page={Some((*page).clone())}
+///         onpagechange={Some(Callback::from(move |p: TablePage| fetch_page(p)))}
+///     />
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct TablePagination {
+    /// Filas por página inicial (modo no controlado)
+    pub page_size: usize,
+    /// Opciones del selector de tamaño de página
+    pub page_size_options: Vec<usize>,
+    /// Total de filas en el servidor (None = client-side)
+    pub total: Option<usize>,
+}
+
+impl TablePagination {
+    pub fn client(page_size: usize) -> Self {
+        Self {
+            page_size,
+            page_size_options: default_page_size_options(),
+            total: None,
+        }
+    }
+
+    pub fn server(total: usize, page_size: usize) -> Self {
+        Self {
+            page_size,
+            page_size_options: default_page_size_options(),
+            total: Some(total),
+        }
+    }
+
+    pub fn with_page_size_options(mut self, options: Vec<usize>) -> Self {
+        self.page_size_options = options;
+        self
+    }
+}
+
+/// Página actual de la tabla (empieza en 1)
+#[derive(Clone, PartialEq, Debug)]
+pub struct TablePage {
+    pub page: usize,
+    pub page_size: usize,
+}
+
+impl TablePage {
+    pub fn new(page: usize, page_size: usize) -> Self {
+        Self { page, page_size }
+    }
+
+    /// La misma página ajustada al rango válido para `total` filas
+    ///
+    /// ```
+    /// use hubermann_ui::TablePage;
+    ///
+    /// assert_eq!(TablePage::new(9, 25).clamped(60), TablePage::new(3, 25));
+    /// assert_eq!(TablePage::new(0, 25).clamped(60), TablePage::new(1, 25));
+    /// assert_eq!(TablePage::new(2, 25).clamped(0), TablePage::new(1, 25));
+    /// ```
+    pub fn clamped(&self, total: usize) -> Self {
+        Self::new(self.page.clamp(1, page_count(total, self.page_size)), self.page_size)
+    }
+
+    /// Rango de filas de esta página dentro de `total` (para slicing client-side)
+    ///
+    /// ```
+    /// use hubermann_ui::TablePage;
+    ///
+    /// assert_eq!(TablePage::new(1, 25).range(60), 0..25);
+    /// assert_eq!(TablePage::new(3, 25).range(60), 50..60);
+    /// // Una página fuera de rango se ajusta a la última
+    /// assert_eq!(TablePage::new(9, 25).range(60), 50..60);
+    /// ```
+    pub fn range(&self, total: usize) -> Range<usize> {
+        let page = self.clamped(total).page;
+        let start = ((page - 1) * self.page_size).min(total);
+        let end = (start + self.page_size).min(total);
+        start..end
+    }
+}
+
+/// Página pedida: controlada si el parent escucha los cambios
+///
+/// Con `onpagechange` la página sale de `page` (o la primera); si no, la
+/// tabla maneja su propio estado. Devuelve la página y el callback que la
+/// cambia.
+#[hook]
+pub(crate) fn use_table_page(
+    pagination: Option<TablePagination>,
+    page: Option<TablePage>,
+    onpagechange: Option<Callback<TablePage>>,
+) -> (TablePage, Callback<TablePage>) {
+    let internal_page = use_state(|| {
+        TablePage::new(1, pagination.as_ref().map(|p| p.page_size).unwrap_or(0))
+    });
+
+    let current = match (&onpagechange, page, &pagination) {
+        (Some(_), Some(page), _) => page,
+        (Some(_), None, Some(pagination)) => TablePage::new(1, pagination.page_size),
+        _ => (*internal_page).clone(),
+    };
+    let set_page = Callback::from(move |page: TablePage| match &onpagechange {
+        Some(callback) => callback.emit(page),
+        None => internal_page.set(page),
+    });
+
+    (current, set_page)
+}
+
+/// Ajusta la página a `total` filas (None sin paginación)
+///
+/// Una página fuera de rango (por ejemplo después de filtrar) pasa a la
+/// última; sin `controlled` el estado interno también se corrige.
+#[hook]
+pub(crate) fn use_clamped_page(
+    page: TablePage,
+    total: Option<usize>,
+    set_page: Callback<TablePage>,
+    controlled: bool,
+) -> TablePage {
+    let clamped = match total {
+        Some(total) => page.clamped(total),
+        None => page.clone(),
+    };
+
+    use_effect_with((page, clamped.clone()), move |(requested, clamped)| {
+        if !controlled && requested != clamped {
+            set_page.emit(clamped.clone());
+        }
+    });
+
+    clamped
+}
+
+/// Filas a mostrar y total a paginar
+///
+/// En client-side se corta la página actual de `order`; en server-side
+/// `order` ya es la página y el total viene de `pagination`.
+pub(crate) fn page_rows(
+    order: Vec<usize>,
+    pagination: Option<&TablePagination>,
+    page: &TablePage,
+) -> (Vec<usize>, usize) {
+    match pagination.map(|pagination| pagination.total) {
+        Some(Some(total)) => (order, total),
+        Some(None) => {
+            let total = order.len();
+            (order[page.range(total)].to_vec(), total)
+        }
+        None => {
+            let total = order.len();
+            (order, total)
+        }
+    }
+}
+
+/// `Pagination` debajo de la tabla; cambiar el tamaño vuelve a la primera página
+pub(crate) fn table_pager(
+    pagination: &TablePagination,
+    page: &TablePage,
+    total: usize,
+    set_page: &Callback<TablePage>,
+) -> Html {
+    let page_size = page.page_size;
+
+    html! {
+        <div class="border-t border-border-default">
+            <Pagination
+                page={page.page}
+                page_size={page_size}
+                total={total}
+                page_size_options={pagination.page_size_options.clone()}
+                onpagechange={set_page.reform(move |page: usize| TablePage::new(page, page_size))}
+                onpagesizechange={Some(set_page.reform(|page_size: usize| TablePage::new(1, page_size)))}
+            />
+        </div>
+    }
+}
@@ -0,0 +1,263 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use gloo::utils::document;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, MouseEvent};
+use yew::prelude::*;
+
+use super::column::TableColumn;
+
+/// Ancho mínimo por default de una columna redimensionable (px)
+pub(crate) const DEFAULT_MIN_WIDTH: f64 = 48.0;
+
+/// Ajusta un ancho a los límites de la columna
+///
+/// ```
+/// use hubermann_ui::clamp_width;
+///
+/// assert_eq!(clamp_width(20.0, 48.0, None), 48.0);
+/// assert_eq!(clamp_width(500.0, 48.0, Some(320.0)), 320.0);
+/// assert_eq!(clamp_width(150.0, 48.0, Some(320.0)), 150.0);
+/// ```
+pub fn clamp_width(width: f64, min: f64, max: Option<f64>) -> f64 {
+    let width = width.max(min);
+    match max {
+        Some(max) => width.min(max.max(min)),
+        None => width,
+    }
+}
+
+/// Offset `left` (px) de cada celda congelada: la suma de los anchos previos
+///
+/// ```
+/// use hubermann_ui::frozen_offsets;
+///
+/// assert_eq!(frozen_offsets(&[40.0, 120.0, 90.0, 200.0], 3), vec![0.0, 40.0, 160.0]);
+/// // Sin medidas todavía no se congela nada
+/// assert!(frozen_offsets(&[], 2).is_empty());
+/// ```
+pub fn frozen_offsets(widths: &[f64], count: usize) -> Vec<f64> {
+    widths
+        .iter()
+        .take(count)
+        .scan(0.0, |left, width| {
+            let offset = *left;
+            *left += width;
+            Some(offset)
+        })
+        .collect()
+}
+
+/// Reemplaza (o agrega) el ancho de una columna
+pub(crate) fn with_column_width(widths: &[(String, f64)], column: &str, width: f64) -> Vec<(String, f64)> {
+    let mut next = widths.to_vec();
+    match next.iter_mut().find(|(id, _)| id == column) {
+        Some((_, current)) => *current = width,
+        None => next.push((column.to_string(), width)),
+    }
+    next
+}
+
+/// Une dos estilos inline opcionales
+pub(crate) fn join_styles(a: Option<String>, b: Option<String>) -> Option<String> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(format!("{}; {}", a, b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Arrastre en curso de un borde de header
+#[derive(Clone, PartialEq)]
+pub(crate) struct ResizeDrag {
+    pub column: String,
+    pub start_x: i32,
+    pub start_width: f64,
+    pub min: f64,
+    pub max: Option<f64>,
+}
+
+/// Estado de anchos de columna con drag-to-resize
+///
+/// Arranca (y se re-sincroniza) desde `initial`; durante el arrastre
+/// escucha mousemove/mouseup en el document y al soltar emite los anchos
+/// por `onresize`. Devuelve los anchos actuales y el callback que inicia
+/// un arrastre.
+#[hook]
+pub(crate) fn use_column_resize(
+    initial: Vec<(String, f64)>,
+    onresize: Option<Callback<Vec<(String, f64)>>>,
+) -> (Rc<Vec<(String, f64)>>, Callback<ResizeDrag>) {
+    let widths = use_state(|| initial.clone());
+    let drag = use_state(|| None::<ResizeDrag>);
+
+    {
+        let widths = widths.clone();
+        use_effect_with(initial, move |initial| widths.set(initial.clone()));
+    }
+
+    {
+        let widths = widths.clone();
+        let drag_handle = drag.clone();
+
+        use_effect_with((*drag).clone(), move |drag| {
+            let listeners = drag.clone().map(|drag| {
+                let document = document();
+                let base = (*widths).clone();
+                let last_width = Rc::new(Cell::new(drag.start_width));
+
+                let on_move = {
+                    let (widths, base, last_width, drag) = (widths.clone(), base.clone(), last_width.clone(), drag.clone());
+                    EventListener::new(&document, "mousemove", move |event| {
+                        if let Some(event) = event.dyn_ref::<MouseEvent>() {
+                            let delta = (event.client_x() - drag.start_x) as f64;
+                            let width = clamp_width(drag.start_width + delta, drag.min, drag.max);
+                            last_width.set(width);
+                            widths.set(with_column_width(&base, &drag.column, width));
+                        }
+                    })
+                };
+
+                let on_up = EventListener::new(&document, "mouseup", move |_| {
+                    let next = with_column_width(&base, &drag.column, last_width.get());
+                    widths.set(next.clone());
+                    if let Some(callback) = &onresize {
+                        callback.emit(next);
+                    }
+                    drag_handle.set(None);
+                });
+
+                (on_move, on_up)
+            });
+
+            move || drop(listeners)
+        });
+    }
+
+    let start = {
+        let drag = drag.clone();
+        Callback::from(move |resize: ResizeDrag| drag.set(Some(resize)))
+    };
+
+    (Rc::new((*widths).clone()), start)
+}
+
+/// Borde arrastrable de un header para cambiar el ancho de su columna
+pub(crate) fn resize_handle<T>(column: &TableColumn<T>, start_resize: &Callback<ResizeDrag>) -> Html {
+    let onmousedown = {
+        let start_resize = start_resize.clone();
+        let (id, min, max) = (column.id.clone(), column.min_width, column.max_width);
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            let handle: HtmlElement = e.target_unchecked_into();
+            let start_width = handle
+                .parent_element()
+                .map_or(min, |th| th.client_width() as f64);
+            start_resize.emit(ResizeDrag {
+                column: id.clone(),
+                start_x: e.client_x(),
+                start_width,
+                min,
+                max,
+            });
+        })
+    };
+
+    html! {
+        <div
+            role="separator"
+            aria-orientation="vertical"
+            aria-label={format!("Resize {}", column.header)}
+            onmousedown={onmousedown}
+            class="absolute right-0 top-0 h-full w-1 cursor-col-resize hover:bg-accent/50 transition-colors"
+        ></div>
+    }
+}
+
+/// Columnas congeladas a la izquierda al scrollear horizontalmente
+///
+/// Incluye las celdas de selección/expansión (`leading`) delante de las
+/// columnas de datos.
+pub(crate) struct FrozenColumns {
+    header_refs: Rc<RefCell<Vec<NodeRef>>>,
+    offsets: Vec<f64>,
+    count: usize,
+    leading: usize,
+}
+
+/// Offsets de las columnas congeladas: salen del ancho medido de los
+/// headers después de cada render
+#[hook]
+pub(crate) fn use_frozen_columns(frozen: usize, leading: usize, column_count: usize) -> FrozenColumns {
+    let header_refs = use_mut_ref(Vec::<NodeRef>::new);
+    header_refs.borrow_mut().resize_with(column_count, NodeRef::default);
+    let count = match frozen {
+        0 => 0,
+        n => (leading + n).min(column_count),
+    };
+    let measured_widths = use_state_eq(Vec::<f64>::new);
+
+    {
+        let measured_widths = measured_widths.clone();
+        let header_refs = header_refs.clone();
+        use_effect(move || {
+            if count > 0 {
+                let widths = header_refs
+                    .borrow()
+                    .iter()
+                    .take(count)
+                    .map(|header| header.cast::<HtmlElement>().map_or(0.0, |th| th.offset_width() as f64))
+                    .collect();
+                measured_widths.set(widths);
+            }
+        });
+    }
+
+    FrozenColumns {
+        offsets: frozen_offsets(&measured_widths, count),
+        header_refs,
+        count,
+        leading,
+    }
+}
+
+impl FrozenColumns {
+    /// Cantidad de celdas de selección/expansión antes de las columnas
+    pub fn leading(&self) -> usize {
+        self.leading
+    }
+
+    /// Ref del header de una celda (para medir su ancho)
+    pub fn header_ref(&self, cell_idx: usize) -> NodeRef {
+        self.header_refs.borrow()[cell_idx].clone()
+    }
+
+    /// Clases y estilo `left` de una celda; vacíos si no está congelada
+    ///
+    /// `background` tiene que ser opaco para tapar lo que scrollea debajo.
+    pub fn cell(&self, cell_idx: usize, background: &'static str) -> (Classes, Option<String>) {
+        match self.offsets.get(cell_idx) {
+            Some(left) => (
+                classes!(
+                    "sticky",
+                    "z-10",
+                    background,
+                    (cell_idx + 1 == self.count).then_some("border-r border-border-default")
+                ),
+                Some(format!("left: {}px", left)),
+            ),
+            None => (classes!(), None),
+        }
+    }
+
+    /// Celdas vacías de las columnas de selección/expansión (filtros, total)
+    pub fn leading_blank(&self, tag: &'static str, background: &'static str) -> Html {
+        (0..self.leading)
+            .map(|cell_idx| {
+                let (frozen_class, frozen_style) = self.cell(cell_idx, background);
+                html! { <@{tag} style={frozen_style} class={classes!("w-10", frozen_class)}></@> }
+            })
+            .collect()
+    }
+}
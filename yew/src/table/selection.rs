@@ -0,0 +1,368 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::cell::TableRow;
+use super::column::Accessor;
+use super::navigation::GridCell;
+use super::resize::FrozenColumns;
+
+/// Modo de selección de filas
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SelectionMode {
+    /// Sin selección (default)
+    None,
+    /// Una fila a la vez (radio)
+    Single,
+    /// Varias filas con checkboxes, "select all" y shift-click para rangos
+    Multi,
+}
+
+/// Identidad estable de una fila
+///
+/// Extrae la clave de cada fila (ej: el id de la orden). La selección y la
+/// reconciliación de filas usan esta clave, así siguen apuntando a la misma
+/// fila aunque cambie el orden o lleguen datos nuevos. Sin `row_key` la
+/// tabla usa la posición de la fila en `rows`.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// html! {
+///     <Table<Order>
+///         columns={columns}
+///         rows={orders}
+///         row_key={Some(TableRowKey::new(|o: &Order| o.id.clone()))}
+///         selection={SelectionMode::Multi}
+///     />
+/// }
+/// ```
+pub struct TableRowKey<T>(Accessor<T, Option<String>>);
+
+impl<T> TableRowKey<T> {
+    pub fn new(key: impl Fn(&T) -> String + 'static) -> Self {
+        Self(Rc::new(move |row| Some(key(row))))
+    }
+
+    /// Clave de una fila; si no tiene, su posición en `rows`
+    pub fn key(&self, row: &T, position: usize) -> String {
+        (self.0)(row).unwrap_or_else(|| position.to_string())
+    }
+}
+
+impl TableRowKey<TableRow> {
+    /// Usa la clave fijada con `TableRow::with_key`; las filas sin clave
+    /// usan su posición, así dos filas nunca comparten key
+    ///
+    /// ```
+    /// use hubermann_ui::{TableCell, TableRow, TableRowKey};
+    ///
+    /// let rows = vec![
+    ///     TableRow::new(vec![TableCell::text("AAPL")]),
+    ///     TableRow::new(vec![TableCell::text("MSFT")]),
+    ///     TableRow::new(vec![TableCell::text("NVDA")]).with_key("nvda"),
+    /// ];
+    /// let row_key = TableRowKey::from_row();
+    ///
+    /// assert_eq!(row_key.key(&rows[0], 0), "0");
+    /// assert_eq!(row_key.key(&rows[1], 1), "1");
+    /// assert_eq!(row_key.key(&rows[2], 2), "nvda");
+    /// ```
+    pub fn from_row() -> Self {
+        Self(Rc::new(|row: &TableRow| row.key.clone()))
+    }
+}
+
+impl<T> Clone for TableRowKey<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for TableRowKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Estado del checkbox "select all" del header
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum SelectAllState {
+    None,
+    Some,
+    All,
+}
+
+/// Cuántas de las filas visibles están seleccionadas
+pub(crate) fn select_all_state(visible: &[String], selected: &[String]) -> SelectAllState {
+    let count = visible.iter().filter(|key| selected.contains(key)).count();
+    match count {
+        0 => SelectAllState::None,
+        n if n == visible.len() => SelectAllState::All,
+        _ => SelectAllState::Some,
+    }
+}
+
+/// Nueva selección al clickear una fila
+///
+/// - Single: la fila pasa a ser la única seleccionada (o se deselecciona)
+/// - Multi: toggle de la fila; con `range_from` (shift-click) se agrega todo
+///   el rango entre la última fila clickeada y esta, en el orden visible
+///
+/// ```
+/// use hubermann_ui::{next_selection, SelectionMode};
+///
+/// let visible: Vec<String> = ["a", "b", "c", "d"].iter().map(|k| k.to_string()).collect();
+///
+/// let selected = next_selection(SelectionMode::Multi, &[], &visible, 1, None);
+/// assert_eq!(selected, vec!["b"]);
+/// // Shift-click desde "b" hasta "d"
+/// let selected = next_selection(SelectionMode::Multi, &selected, &visible, 3, Some(1));
+/// assert_eq!(selected, vec!["b", "c", "d"]);
+/// // Single reemplaza la selección
+/// let selected = next_selection(SelectionMode::Single, &selected, &visible, 0, None);
+/// assert_eq!(selected, vec!["a"]);
+/// ```
+pub fn next_selection(
+    mode: SelectionMode,
+    selected: &[String],
+    visible: &[String],
+    position: usize,
+    range_from: Option<usize>,
+) -> Vec<String> {
+    let Some(key) = visible.get(position) else {
+        return selected.to_vec();
+    };
+
+    match mode {
+        SelectionMode::None => Vec::new(),
+        SelectionMode::Single => {
+            if selected.len() == 1 && selected[0] == *key {
+                Vec::new()
+            } else {
+                vec![key.clone()]
+            }
+        }
+        SelectionMode::Multi => match range_from {
+            Some(anchor) if anchor < visible.len() => {
+                let (start, end) = (anchor.min(position), anchor.max(position));
+                let mut next = selected.to_vec();
+                for key in &visible[start..=end] {
+                    if !next.contains(key) {
+                        next.push(key.clone());
+                    }
+                }
+                next
+            }
+            _ => {
+                if selected.contains(key) {
+                    selected.iter().filter(|k| *k != key).cloned().collect()
+                } else {
+                    let mut next = selected.to_vec();
+                    next.push(key.clone());
+                    next
+                }
+            }
+        },
+    }
+}
+
+/// Key de cada fila de `rows`: `row_key` o su posición
+pub(crate) fn row_keys<T>(rows: &[T], row_key: Option<&TableRowKey<T>>) -> Vec<String> {
+    rows.iter()
+        .enumerate()
+        .map(|(idx, row)| match row_key {
+            Some(row_key) => row_key.key(row, idx),
+            None => idx.to_string(),
+        })
+        .collect()
+}
+
+/// Selección de filas de un render
+#[derive(Clone)]
+pub(crate) struct RowSelection {
+    pub mode: SelectionMode,
+    /// Keys seleccionadas
+    pub selected: Rc<Vec<String>>,
+    /// Keys de las filas mostradas, en orden
+    visible: Rc<Vec<String>>,
+    set_selected: Callback<Vec<String>>,
+    /// Última fila clickeada (para shift-click)
+    anchor: Rc<RefCell<Option<usize>>>,
+    all_state: SelectAllState,
+    select_all_ref: NodeRef,
+}
+
+/// Selección: controlada si el parent escucha los cambios
+///
+/// `visible` son las keys de las filas mostradas: "select all" y los rangos
+/// de shift-click se aplican sobre ellas.
+#[hook]
+pub(crate) fn use_row_selection(
+    mode: SelectionMode,
+    selected: Option<Vec<String>>,
+    onselectionchange: Option<Callback<Vec<String>>>,
+    visible: Rc<Vec<String>>,
+) -> RowSelection {
+    let internal_selected = use_state(Vec::<String>::new);
+    let anchor = use_mut_ref(|| None::<usize>);
+    let selected = Rc::new(match onselectionchange {
+        Some(_) => selected.unwrap_or_default(),
+        None => (*internal_selected).clone(),
+    });
+    let all_state = select_all_state(&visible, &selected);
+
+    let set_selected = Callback::from(move |selected: Vec<String>| match &onselectionchange {
+        Some(callback) => callback.emit(selected),
+        None => internal_selected.set(selected),
+    });
+
+    let select_all_ref = use_node_ref();
+    {
+        let select_all_ref = select_all_ref.clone();
+        use_effect_with(all_state, move |state| {
+            if let Some(input) = select_all_ref.cast::<HtmlInputElement>() {
+                input.set_indeterminate(*state == SelectAllState::Some);
+            }
+        });
+    }
+
+    RowSelection {
+        mode,
+        selected,
+        visible,
+        set_selected,
+        anchor,
+        all_state,
+        select_all_ref,
+    }
+}
+
+impl RowSelection {
+    pub fn is_enabled(&self) -> bool {
+        self.mode != SelectionMode::None
+    }
+
+    pub fn is_selected(&self, key: &str) -> bool {
+        self.is_enabled() && self.selected.iter().any(|selected| selected == key)
+    }
+
+    /// Selecciona la fila en `position` del orden visible; con shift
+    /// extiende el rango desde la última fila clickeada
+    pub fn select_row(&self, position: usize) -> Rc<dyn Fn(bool)> {
+        let mode = self.mode;
+        let selected = self.selected.clone();
+        let visible = self.visible.clone();
+        let anchor = self.anchor.clone();
+        let set_selected = self.set_selected.clone();
+        Rc::new(move |shift: bool| {
+            let range_from = if shift { *anchor.borrow() } else { None };
+            *anchor.borrow_mut() = Some(position);
+            set_selected.emit(next_selection(mode, &selected, &visible, position, range_from));
+        })
+    }
+
+    /// Selecciona o deselecciona todas las filas mostradas
+    fn toggle_all(&self) -> Callback<MouseEvent> {
+        let set_selected = self.set_selected.clone();
+        let selected = self.selected.clone();
+        let visible = self.visible.clone();
+        let all_state = self.all_state;
+        Callback::from(move |_: MouseEvent| {
+            let next = if all_state == SelectAllState::All {
+                selected.iter().filter(|key| !visible.contains(key)).cloned().collect()
+            } else {
+                let mut next = (*selected).clone();
+                next.extend(visible.iter().filter(|key| !selected.contains(key)).cloned());
+                next
+            };
+            set_selected.emit(next);
+        })
+    }
+}
+
+/// Barra de acciones masivas, visible con filas seleccionadas
+pub(crate) fn bulk_actions_bar(selection: &RowSelection, actions: &Html) -> Html {
+    if selection.selected.is_empty() {
+        return html! {};
+    }
+
+    let clear = {
+        let set_selected = selection.set_selected.clone();
+        Callback::from(move |_: MouseEvent| set_selected.emit(Vec::new()))
+    };
+
+    html! {
+        <div class="flex items-center justify-between gap-3 px-3 py-2 bg-accent/10 border-b border-border-default">
+            <span class="text-sm font-medium text-text-primary">
+                {format!("{} selected", selection.selected.len())}
+            </span>
+            <div class="flex items-center gap-2">
+                {actions.clone()}
+                <button
+                    type="button"
+                    onclick={clear}
+                    class="px-2 py-1 rounded text-xs text-text-tertiary hover:bg-bg-tertiary hover:text-text-primary transition-colors"
+                >
+                    {"Clear"}
+                </button>
+            </div>
+        </div>
+    }
+}
+
+/// Header de la columna de selección: "select all" en Multi
+pub(crate) fn select_all_header(selection: &RowSelection, frozen: &FrozenColumns) -> Html {
+    let (frozen_class, frozen_style) = frozen.cell(0, "bg-bg-tertiary");
+
+    html! {
+        <th
+            ref={frozen.header_ref(0)}
+            style={frozen_style}
+            class={classes!("w-10", "px-3", "py-3", "text-left", frozen_class)}
+        >
+            if selection.mode == SelectionMode::Multi {
+                <input
+                    ref={selection.select_all_ref.clone()}
+                    type="checkbox"
+                    aria-label="Select all rows"
+                    checked={selection.all_state == SelectAllState::All}
+                    onclick={selection.toggle_all()}
+                    class="h-4 w-4 rounded border-border-default bg-bg-input accent-accent cursor-pointer"
+                />
+            }
+        </th>
+    }
+}
+
+/// Celda con el checkbox (o radio en Single) de una fila
+pub(crate) fn select_cell(
+    selection: &RowSelection,
+    selected: bool,
+    select_row: Rc<dyn Fn(bool)>,
+    grid: GridCell,
+    (frozen_class, frozen_style): (Classes, Option<String>),
+) -> Html {
+    let onselect = Callback::from(move |e: MouseEvent| select_row(e.shift_key()));
+
+    html! {
+        <td
+            role={grid.role}
+            tabindex={grid.tabindex}
+            aria-colindex={grid.colindex}
+            onfocusin={grid.onfocusin}
+            style={frozen_style}
+            class={classes!("w-10", "px-3", "py-3", frozen_class)}
+        >
+            <input
+                type={if selection.mode == SelectionMode::Single { "radio" } else { "checkbox" }}
+                tabindex={grid.inner_tabindex}
+                aria-label="Select row"
+                checked={selected}
+                onclick={onselect}
+                class="h-4 w-4 rounded border-border-default bg-bg-input accent-accent cursor-pointer"
+            />
+        </td>
+    }
+}
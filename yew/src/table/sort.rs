@@ -0,0 +1,242 @@
+use std::cmp::Ordering;
+
+use yew::prelude::*;
+
+use super::column::TableColumn;
+use crate::format::Locale;
+
+/// Clave de orden de una celda
+///
+/// Los números se comparan numéricamente, el texto sin distinguir
+/// mayúsculas. Los números van antes que el texto y las celdas vacías
+/// siempre al final, sin importar la dirección.
+#[derive(Clone, PartialEq, Debug)]
+pub enum SortKey {
+    Number(f64),
+    Text(String),
+    Empty,
+}
+
+impl SortKey {
+    /// Deriva una clave desde texto formateado en en-US
+    ///
+    /// Ignora símbolos de moneda, separadores de miles, signo `+` y `%`,
+    /// y entiende los sufijos K/M/B ("45.2M" → 45_200_000). Para texto con
+    /// otros separadores ver `parse_locale`.
+    ///
+    /// ```
+    /// use hubermann_ui::SortKey;
+    ///
+    /// assert_eq!(SortKey::parse("$1,182.45"), SortKey::Number(1182.45));
+    /// assert_eq!(SortKey::parse("+2.3%"), SortKey::Number(2.3));
+    /// assert_eq!(SortKey::parse("-1.2%"), SortKey::Number(-1.2));
+    /// assert_eq!(SortKey::parse("45.2M"), SortKey::Number(45_200_000.0));
+    /// assert_eq!(SortKey::parse("AAPL"), SortKey::Text("AAPL".to_string()));
+    /// assert_eq!(SortKey::parse("  "), SortKey::Empty);
+    /// ```
+    pub fn parse(content: &str) -> Self {
+        Self::parse_locale(content, Locale::EnUs)
+    }
+
+    /// Deriva una clave desde texto formateado con los separadores de
+    /// `locale`. Las celdas de texto en es-AR necesitan esta clave explícita
+    /// (`with_sort_key`); `TableCell::numeric` y `delta` ya guardan el valor.
+    ///
+    /// ```
+    /// use hubermann_ui::{Locale, SortKey};
+    ///
+    /// assert_eq!(SortKey::parse_locale("1.234,56", Locale::EsAr), SortKey::Number(1234.56));
+    /// assert_eq!(SortKey::parse_locale("$ -12,5", Locale::EsAr), SortKey::Number(-12.5));
+    /// assert_eq!(SortKey::parse_locale("+3,1 %", Locale::EsAr), SortKey::Number(3.1));
+    /// assert_eq!(SortKey::parse_locale("1,2M", Locale::EsAr), SortKey::Number(1_200_000.0));
+    /// // Con el parse en-US el punto de miles se leería como decimal
+    /// assert_eq!(SortKey::parse("1.234,56"), SortKey::Number(1.23456));
+    /// ```
+    pub fn parse_locale(content: &str, locale: Locale) -> Self {
+        let trimmed = content.trim();
+        if trimmed.is_empty() {
+            return SortKey::Empty;
+        }
+
+        let thousands = locale.thousands_separator();
+        let decimal = locale.decimal_separator();
+        let cleaned: String = trimmed
+            .replace('\u{2212}', "-")
+            .chars()
+            .filter(|c| *c != thousands && !matches!(c, '$' | '€' | '£' | '+' | '%' | ' '))
+            .map(|c| if c == decimal { '.' } else { c })
+            .collect();
+
+        let (number, multiplier) = match cleaned.chars().last() {
+            Some('K') | Some('k') => (&cleaned[..cleaned.len() - 1], 1e3),
+            Some('M') => (&cleaned[..cleaned.len() - 1], 1e6),
+            Some('B') => (&cleaned[..cleaned.len() - 1], 1e9),
+            _ => (cleaned.as_str(), 1.0),
+        };
+
+        match number.parse::<f64>() {
+            Ok(value) if value.is_finite() => SortKey::Number(value * multiplier),
+            _ => SortKey::Text(trimmed.to_string()),
+        }
+    }
+
+    /// Compara dos claves en orden ascendente
+    pub fn compare(&self, other: &SortKey) -> Ordering {
+        match (self, other) {
+            (SortKey::Number(a), SortKey::Number(b)) => a.total_cmp(b),
+            (SortKey::Text(a), SortKey::Text(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+            (SortKey::Number(_), SortKey::Text(_)) => Ordering::Less,
+            (SortKey::Text(_), SortKey::Number(_)) => Ordering::Greater,
+            (SortKey::Empty, SortKey::Empty) => Ordering::Equal,
+            (SortKey::Empty, _) => Ordering::Greater,
+            (_, SortKey::Empty) => Ordering::Less,
+        }
+    }
+}
+
+/// Dirección de orden de una columna
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// Estado de orden de la tabla: qué columna (por id) y en qué dirección
+#[derive(Clone, PartialEq, Debug)]
+pub struct TableSort {
+    pub column: String,
+    pub direction: SortDirection,
+}
+
+impl TableSort {
+    pub fn asc(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    pub fn desc(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Descending,
+        }
+    }
+
+    /// Próximo estado al clickear un header: asc → desc → sin orden.
+    /// Clickear otra columna arranca siempre en asc.
+    ///
+    /// ```
+    /// use hubermann_ui::TableSort;
+    ///
+    /// let sort = TableSort::next(None, "price");
+    /// assert_eq!(sort, Some(TableSort::asc("price")));
+    /// let sort = TableSort::next(sort.as_ref(), "price");
+    /// assert_eq!(sort, Some(TableSort::desc("price")));
+    /// assert_eq!(TableSort::next(sort.as_ref(), "price"), None);
+    /// assert_eq!(TableSort::next(sort.as_ref(), "symbol"), Some(TableSort::asc("symbol")));
+    /// ```
+    pub fn next(current: Option<&TableSort>, column: &str) -> Option<TableSort> {
+        match current {
+            Some(sort) if sort.column == column => match sort.direction {
+                SortDirection::Ascending => Some(TableSort::desc(column)),
+                SortDirection::Descending => None,
+            },
+            _ => Some(TableSort::asc(column)),
+        }
+    }
+}
+
+/// Índices de `rows` en el orden a mostrar (sort estable).
+/// Si la columna del sort no existe se respeta el orden original.
+pub(crate) fn sorted_indices<T>(
+    rows: &[T],
+    columns: &[TableColumn<T>],
+    sort: Option<&TableSort>,
+) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..rows.len()).collect();
+
+    let Some(sort) = sort else {
+        return indices;
+    };
+    let Some(column) = columns.iter().find(|c| c.id == sort.column) else {
+        return indices;
+    };
+
+    let keys: Vec<SortKey> = rows.iter().map(|row| column.sort_key(row)).collect();
+
+    indices.sort_by(|&a, &b| {
+        let (a, b) = (&keys[a], &keys[b]);
+        match (a, b, sort.direction) {
+            // Vacíos al final en ambas direcciones
+            (SortKey::Empty, _, _) | (_, SortKey::Empty, _) => a.compare(b),
+            (_, _, SortDirection::Ascending) => a.compare(b),
+            (_, _, SortDirection::Descending) => b.compare(a),
+        }
+    });
+
+    indices
+}
+
+/// Orden de la tabla: controlado si el parent escucha los cambios
+///
+/// Sin `onsort` arranca en `initial` y maneja su propio estado. Devuelve
+/// el orden a mostrar y el callback que lo cambia.
+#[hook]
+pub(crate) fn use_table_sort(
+    initial: Option<TableSort>,
+    sort: Option<TableSort>,
+    onsort: Option<Callback<Option<TableSort>>>,
+) -> (Option<TableSort>, Callback<Option<TableSort>>) {
+    let internal_sort = use_state(|| initial);
+
+    let current = match onsort {
+        Some(_) => sort,
+        None => (*internal_sort).clone(),
+    };
+    let set_sort = Callback::from(move |next: Option<TableSort>| match &onsort {
+        Some(callback) => callback.emit(next),
+        None => internal_sort.set(next),
+    });
+
+    (current, set_sort)
+}
+
+/// Header clickeable de una columna ordenable con el indicador de dirección
+///
+/// Devuelve el `aria-sort` del header y su contenido.
+pub(crate) fn sort_header<T>(
+    column: &TableColumn<T>,
+    current: Option<&TableSort>,
+    set_sort: &Callback<Option<TableSort>>,
+) -> (&'static str, Html) {
+    let direction = current
+        .filter(|sort| sort.column == column.id)
+        .map(|sort| sort.direction);
+
+    let (indicator, indicator_class, aria_sort) = match direction {
+        Some(SortDirection::Ascending) => ("↑", "text-accent", "ascending"),
+        Some(SortDirection::Descending) => ("↓", "text-accent", "descending"),
+        None => ("↕", "text-text-muted", "none"),
+    };
+
+    let onclick = {
+        let next = TableSort::next(current, &column.id);
+        set_sort.reform(move |_: MouseEvent| next.clone())
+    };
+
+    let content = html! {
+        <button
+            type="button"
+            onclick={onclick}
+            class="inline-flex items-center gap-1 hover:text-accent transition-colors"
+        >
+            {&column.header}
+            <span class={classes!("text-xs", indicator_class)}>
+                {indicator}
+            </span>
+        </button>
+    };
+
+    (aria_sort, content)
+}
@@ -0,0 +1,192 @@
+use std::ops::Range;
+
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+/// Configuración de renderizado virtualizado (windowing)
+///
+/// Con virtualización la tabla vive en un contenedor de alto fijo con
+/// scroll vertical y header sticky, y solo monta las filas visibles más
+/// `overscan` filas arriba y abajo. El resto se reemplaza por dos filas
+/// espaciadoras que mantienen el alto total del scroll.
+///
+/// Requiere filas de alto uniforme: con `RowHeight::Fixed` se usa el alto
+/// dado, con `RowHeight::Measured` se arranca con un estimado y se mide la
+/// primera fila montada.
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// html! {
+///     <Table<Fill>
+///         columns={columns}
+///         rows={fills}  // 50k filas
+///         virtualize={Some(TableVirtualization::fixed(45.0, 480.0).with_overscan(12))}
+///     />
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct TableVirtualization {
+    /// Alto de cada fila
+    pub row_height: RowHeight,
+    /// Alto del contenedor con scroll en px
+    pub height: f64,
+    /// Filas extra montadas arriba y abajo de la ventana visible
+    pub overscan: usize,
+}
+
+/// Alto de fila para la virtualización
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RowHeight {
+    /// Alto conocido en px
+    Fixed(f64),
+    /// Estimado inicial en px, reemplazado por el alto real de la primera fila
+    Measured(f64),
+}
+
+impl TableVirtualization {
+    pub fn fixed(row_height: f64, height: f64) -> Self {
+        Self {
+            row_height: RowHeight::Fixed(row_height),
+            height,
+            overscan: 8,
+        }
+    }
+
+    pub fn measured(estimate: f64, height: f64) -> Self {
+        Self {
+            row_height: RowHeight::Measured(estimate),
+            height,
+            overscan: 8,
+        }
+    }
+
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+}
+
+impl RowHeight {
+    pub(crate) fn initial(&self) -> f64 {
+        match self {
+            RowHeight::Fixed(height) | RowHeight::Measured(height) => *height,
+        }
+    }
+}
+
+/// Rango de filas a montar para un scroll dado
+///
+/// ```
+/// use hubermann_ui::visible_range;
+///
+/// // 1000 filas de 40px en un viewport de 400px, sin scroll
+/// assert_eq!(visible_range(1000, 40.0, 400.0, 0.0, 5), 0..15);
+/// // Scrolleado a la fila 100
+/// assert_eq!(visible_range(1000, 40.0, 400.0, 4000.0, 5), 95..115);
+/// // Al final nunca se pasa del total
+/// assert_eq!(visible_range(1000, 40.0, 400.0, 39_600.0, 5), 985..1000);
+/// ```
+pub fn visible_range(
+    total: usize,
+    row_height: f64,
+    viewport_height: f64,
+    scroll_top: f64,
+    overscan: usize,
+) -> Range<usize> {
+    if total == 0 || row_height <= 0.0 {
+        return 0..total;
+    }
+
+    let first = (scroll_top.max(0.0) / row_height).floor() as usize;
+    let visible = (viewport_height / row_height).ceil() as usize;
+
+    let start = first.saturating_sub(overscan).min(total);
+    let end = (first + visible + overscan).min(total);
+
+    start..end
+}
+
+/// Ventana de filas montadas de un body virtualizado
+pub(crate) struct VirtualWindow {
+    /// Filas a montar (todas sin virtualización)
+    pub range: Range<usize>,
+    /// Alto de fila (medido o fijo)
+    pub row_height: f64,
+    /// Ref de la primera fila montada, para medir su alto
+    pub first_row_ref: NodeRef,
+    pub onscroll: Callback<Event>,
+}
+
+/// Primera fila visible según el scroll del contenedor y alto de fila
+/// (medido o fijo) de una tabla con `rows` filas
+#[hook]
+pub(crate) fn use_virtual_window(virtualize: Option<TableVirtualization>, rows: usize) -> VirtualWindow {
+    let first_visible = use_state_eq(|| 0usize);
+    let measured_height = use_state_eq(|| {
+        virtualize.as_ref().map(|v| v.row_height.initial()).unwrap_or(0.0)
+    });
+    let first_row_ref = use_node_ref();
+
+    {
+        let measured_height = measured_height.clone();
+        let first_row_ref = first_row_ref.clone();
+        let measure = matches!(
+            virtualize.as_ref().map(|v| v.row_height),
+            Some(RowHeight::Measured(_))
+        );
+
+        use_effect(move || {
+            if measure {
+                if let Some(row) = first_row_ref.cast::<HtmlElement>() {
+                    let height = row.offset_height() as f64;
+                    if height > 0.0 {
+                        measured_height.set(height);
+                    }
+                }
+            }
+        });
+    }
+
+    let row_height = match virtualize.as_ref().map(|v| v.row_height) {
+        Some(RowHeight::Fixed(height)) => height,
+        _ => *measured_height,
+    };
+
+    let range = match &virtualize {
+        Some(virtualize) => visible_range(
+            rows,
+            row_height,
+            virtualize.height,
+            *first_visible as f64 * row_height,
+            virtualize.overscan,
+        ),
+        None => 0..rows,
+    };
+
+    let onscroll = Callback::from(move |e: Event| {
+        let container: HtmlElement = e.target_unchecked_into();
+        if row_height > 0.0 {
+            first_visible.set((container.scroll_top() as f64 / row_height).floor() as usize);
+        }
+    });
+
+    VirtualWindow {
+        range,
+        row_height,
+        first_row_ref,
+        onscroll,
+    }
+}
+
+/// Fila espaciadora con el alto de `rows` filas no montadas
+pub(crate) fn spacer_row(rows: usize, row_height: f64, colspan: usize) -> Html {
+    if rows == 0 {
+        return html! {};
+    }
+    let style = format!("height: {}px", rows as f64 * row_height);
+    html! {
+        <tr aria-hidden="true" style={style}>
+            <td colspan={colspan.to_string()} class="p-0"></td>
+        </tr>
+    }
+}
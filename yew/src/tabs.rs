@@ -17,7 +17,7 @@ use yew::prelude::*;
 /// - `onchange`: Callback<String> - Handler cuando cambia tab
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// let tabs = vec![
@@ -0,0 +1,142 @@
+use yew::prelude::*;
+
+use crate::format::{Locale, NumberFormat};
+use crate::stats_card::ChangeType;
+
+/// Cotización de la `TickerTape`
+#[derive(Clone, PartialEq)]
+pub struct Quote {
+    pub symbol: String,
+    pub price: f64,
+    /// Variación porcentual (ej: 1.25 = +1.25%)
+    pub change: f64,
+    pub change_type: ChangeType,
+}
+
+impl Quote {
+    /// Cotización con el tipo de cambio según el signo de `change`
+    ///
+    /// ```
+    /// use hubermann_ui::{ChangeType, Quote};
+    ///
+    /// let quote = Quote::new("AAPL", 189.84, -1.2);
+    /// assert!(quote.change_type == ChangeType::Bearish);
+    /// ```
+    pub fn new(symbol: impl Into<String>, price: f64, change: f64) -> Self {
+        Self {
+            symbol: symbol.into(),
+            price,
+            change,
+            change_type: ChangeType::from_delta(change),
+        }
+    }
+}
+
+/// TickerTape - Cinta de cotizaciones que se desplaza en loop
+///
+/// Para el header de dashboards: símbolo, precio y cambio de cada quote,
+/// con los mismos colores y flechas que el badge de `StatsCard`. El
+/// contenido va duplicado para que el loop no tenga cortes; se pausa con
+/// hover y con `prefers-reduced-motion` queda quieta y se puede
+/// scrollear a mano.
+///
+/// Usa la animación `ticker` de `tailwind.config.base.js`.
+///
+/// Respeta el visual language:
+/// - Franja bg-secondary con border arriba y abajo
+/// - Text xs, precios en font-mono con tabular-nums
+/// - Cambio como badge bullish/bearish/neutral con flecha
+/// - Sin animación con motion-reduce
+///
+/// # Props
+/// - `quotes`: Vec<Quote> - Cotizaciones en el orden a mostrar
+/// - `decimals`: usize - Decimales de los precios (default: 2)
+/// - `locale`: Locale - Separadores de precios y cambios (default: en-US)
+/// - `duration`: f64 - Segundos de una vuelta completa (default: 40)
+/// - `label`: Option<String> - Descripción para lectores de pantalla
+///
+/// # Ejemplo
+/// ```rust,ignore
+/// use hubermann_ui::*;
+///
+/// html! {
+///     <TickerTape
+///         quotes={vec![
+///             Quote::new("BTC", 67_412.5, 2.31),
+///             Quote::new("ETH", 3_281.04, -0.84),
+///             Quote::new("SPX", 5_431.6, 0.0),
+///         ]}
+///         duration={60.0}
+///     />
+/// }
+/// ```
+#[derive(Properties, PartialEq)]
+pub struct TickerTapeProps {
+    pub quotes: Vec<Quote>,
+    #[prop_or(2)]
+    pub decimals: usize,
+    #[prop_or_default]
+    pub locale: Locale,
+    #[prop_or(40.0)]
+    pub duration: f64,
+    #[prop_or_default]
+    pub label: Option<String>,
+}
+
+#[function_component(TickerTape)]
+pub fn ticker_tape(props: &TickerTapeProps) -> Html {
+    let price_format = NumberFormat::new(props.decimals).with_locale(props.locale);
+    let change_format = NumberFormat::change(2).with_locale(props.locale);
+    let items = || {
+        props.quotes.iter().map(|quote| {
+            html! {
+                <li class="flex items-center gap-2 px-4 py-2 border-r border-border-subtle whitespace-nowrap">
+                    <span class="font-semibold text-text-primary">{&quote.symbol}</span>
+                    <span class="font-mono tabular-nums text-text-secondary">
+                        {price_format.format(quote.price)}
+                    </span>
+                    <span class={classes!(
+                        "inline-flex",
+                        "items-center",
+                        "px-1.5",
+                        "py-0.5",
+                        "rounded",
+                        "border",
+                        "font-mono",
+                        "tabular-nums",
+                        quote.change_type.classes()
+                    )}>
+                        {format!("{} {}", quote.change_type.arrow(), change_format.format(quote.change))}
+                    </span>
+                </li>
+            }
+        })
+    };
+
+    html! {
+        <div
+            role="region"
+            aria-label={props.label.clone().unwrap_or_else(|| "Ticker".to_string())}
+            class="group overflow-hidden motion-reduce:overflow-x-auto bg-bg-secondary border-y border-border-default text-xs"
+        >
+            <div
+                class={classes!(
+                    "flex",
+                    "w-max",
+                    "animate-ticker",
+                    "group-hover:[animation-play-state:paused]",
+                    "motion-reduce:animate-none",
+                )}
+                style={format!("animation-duration: {}s", props.duration)}
+            >
+                <ul class="flex shrink-0 items-center">
+                    { for items() }
+                </ul>
+                // Segunda copia para el loop; los lectores de pantalla la saltean
+                <ul class="flex shrink-0 items-center motion-reduce:hidden" aria-hidden="true">
+                    { for items() }
+                </ul>
+            </div>
+        </div>
+    }
+}
@@ -20,7 +20,7 @@ use gloo::timers::callback::Timeout;
 /// - `onclose`: Option<Callback> - Handler al cerrar
 ///
 /// # Ejemplo
-/// ```rust
+/// ```rust,ignore
 /// use hubermann_ui::*;
 ///
 /// let (show_toast, set_show_toast) = use_state(|| false);